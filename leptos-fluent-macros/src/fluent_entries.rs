@@ -1,7 +1,7 @@
 use crate::{FluentFilePaths, FluentResources};
 use fluent_bundle::FluentResource;
 use fluent_syntax::ast::{
-    CallArguments, Expression, InlineExpression, PatternElement,
+    CallArguments, Expression, InlineExpression, Pattern, PatternElement,
 };
 use std::collections::HashMap;
 use std::path::Path;
@@ -9,6 +9,14 @@ use std::rc::Rc;
 
 pub(crate) type FluentEntries = HashMap<Rc<String>, Vec<FluentEntry>>;
 
+/// Prefix of the identifiers of the messages that translate route segments,
+/// like `leptos_fluent::routes::MESSAGE_PREFIX`.
+pub(crate) const ROUTE_MESSAGE_PREFIX: &str = "route-";
+
+/// Calls to Fluent functions by language, as `(message_name, function)`.
+pub(crate) type FluentFunctionCalls =
+    HashMap<Rc<String>, Vec<(String, String)>>;
+
 #[cfg_attr(any(debug_assertions, feature = "tracing"), derive(Debug))]
 #[derive(Clone, PartialEq)]
 enum Placeable {
@@ -45,6 +53,65 @@ pub(crate) struct FluentEntry {
     pub(crate) placeables: Vec<String>,
 }
 
+fn placeables_from_pattern(
+    pattern: &Pattern<&str>,
+    message_reference_found: &mut bool,
+) -> Vec<Placeable> {
+    let mut placeables = Vec::new();
+    for element in &pattern.elements {
+        if let PatternElement::Placeable { expression } = element {
+            if let Expression::Inline(InlineExpression::VariableReference {
+                id,
+            }) = expression
+            {
+                placeables.push(id.name.to_string().into());
+            } else if let Expression::Inline(
+                InlineExpression::FunctionReference {
+                    arguments: CallArguments { positional, .. },
+                    ..
+                },
+            ) = expression
+            {
+                for arg in positional {
+                    if let InlineExpression::VariableReference { id } = arg {
+                        placeables.push(id.name.to_string().into());
+                    }
+                }
+            } else if let Expression::Select {
+                selector: InlineExpression::VariableReference { id },
+                ..
+            } = expression
+            {
+                placeables.push(id.name.to_string().into());
+            } else if let Expression::Select {
+                selector:
+                    InlineExpression::FunctionReference {
+                        arguments: CallArguments { positional, .. },
+                        ..
+                    },
+                ..
+            } = expression
+            {
+                for arg in positional {
+                    if let fluent_syntax::ast::InlineExpression::VariableReference {
+                        id
+                    } = arg {
+                        placeables.push(id.name.to_string().into());
+                    }
+                }
+            } else if let Expression::Inline(
+                InlineExpression::MessageReference { id, .. },
+            ) = expression
+            {
+                placeables
+                    .push(Placeable::MessageReference(id.name.to_string()));
+                *message_reference_found = true;
+            }
+        }
+    }
+    placeables
+}
+
 fn get_fluent_entries_from_resource(
     resource: &FluentResource,
 ) -> (Vec<FluentEntry>, Vec<String>) {
@@ -56,65 +123,26 @@ fn get_fluent_entries_from_resource(
     for entry in resource.entries() {
         if let fluent_syntax::ast::Entry::Message(msg) = entry {
             if let Some(value) = &msg.value {
-                let mut placeables = Vec::new();
-                for element in &value.elements {
-                    if let PatternElement::Placeable { expression } = element {
-                        if let Expression::Inline(
-                            InlineExpression::VariableReference { id },
-                        ) = expression
-                        {
-                            placeables.push(id.name.to_string().into());
-                        } else if let Expression::Inline(
-                            InlineExpression::FunctionReference {
-                                arguments: CallArguments { positional, .. },
-                                ..
-                            },
-                        ) = expression
-                        {
-                            for arg in positional {
-                                if let InlineExpression::VariableReference {
-                                    id,
-                                } = arg
-                                {
-                                    placeables.push(id.name.to_string().into());
-                                }
-                            }
-                        } else if let Expression::Select {
-                            selector: InlineExpression::VariableReference { id },
-                            ..
-                        } = expression
-                        {
-                            placeables.push(id.name.to_string().into());
-                        } else if let Expression::Select {
-                            selector:
-                                InlineExpression::FunctionReference {
-                                    arguments: CallArguments { positional, .. },
-                                    ..
-                                },
-                            ..
-                        } = expression
-                        {
-                            for arg in positional {
-                                if let fluent_syntax::ast::InlineExpression::VariableReference {
-                                    id
-                                } = arg {
-                                    placeables.push(id.name.to_string().into());
-                                }
-                            }
-                        } else if let Expression::Inline(
-                            InlineExpression::MessageReference { id, .. },
-                        ) = expression
-                        {
-                            placeables.push(Placeable::MessageReference(
-                                id.name.to_string(),
-                            ));
-                            message_reference_found = true;
-                        }
-                    }
-                }
                 entries.push(MaybeReferencedFluentEntry {
                     message_name: msg.id.name.to_string(),
-                    placeables,
+                    placeables: placeables_from_pattern(
+                        value,
+                        &mut message_reference_found,
+                    ),
+                });
+            }
+
+            // attributes are exposed as `message.attribute` entries
+            for attribute in &msg.attributes {
+                entries.push(MaybeReferencedFluentEntry {
+                    message_name: format!(
+                        "{}.{}",
+                        msg.id.name, attribute.id.name
+                    ),
+                    placeables: placeables_from_pattern(
+                        &attribute.value,
+                        &mut message_reference_found,
+                    ),
                 });
             }
         }
@@ -347,6 +375,155 @@ pub(crate) fn build_fluent_entries(
     fluent_entries
 }
 
+fn function_calls_from_inline_expression(
+    expression: &InlineExpression<&str>,
+    functions: &mut Vec<String>,
+) {
+    match expression {
+        InlineExpression::FunctionReference { id, arguments } => {
+            functions.push(id.name.to_string());
+            for arg in &arguments.positional {
+                function_calls_from_inline_expression(arg, functions);
+            }
+            for arg in &arguments.named {
+                function_calls_from_inline_expression(&arg.value, functions);
+            }
+        }
+        InlineExpression::Placeable { expression } => {
+            function_calls_from_expression(expression, functions);
+        }
+        _ => {}
+    }
+}
+
+fn function_calls_from_expression(
+    expression: &Expression<&str>,
+    functions: &mut Vec<String>,
+) {
+    match expression {
+        Expression::Inline(inline) => {
+            function_calls_from_inline_expression(inline, functions);
+        }
+        Expression::Select { selector, variants } => {
+            function_calls_from_inline_expression(selector, functions);
+            for variant in variants {
+                function_calls_from_pattern(&variant.value, functions);
+            }
+        }
+    }
+}
+
+fn function_calls_from_pattern(
+    pattern: &Pattern<&str>,
+    functions: &mut Vec<String>,
+) {
+    for element in &pattern.elements {
+        if let PatternElement::Placeable { expression } = element {
+            function_calls_from_expression(expression, functions);
+        }
+    }
+}
+
+fn get_function_calls_from_resource(
+    resource: &FluentResource,
+) -> Vec<(String, String)> {
+    let mut calls = Vec::new();
+    for entry in resource.entries() {
+        let (name, value, attributes) = match entry {
+            fluent_syntax::ast::Entry::Message(msg) => {
+                (msg.id.name.to_string(), msg.value.as_ref(), &msg.attributes)
+            }
+            fluent_syntax::ast::Entry::Term(term) => (
+                format!("-{}", term.id.name),
+                Some(&term.value),
+                &term.attributes,
+            ),
+            _ => continue,
+        };
+
+        let mut functions = Vec::new();
+        if let Some(value) = value {
+            function_calls_from_pattern(value, &mut functions);
+        }
+        for attribute in attributes {
+            function_calls_from_pattern(&attribute.value, &mut functions);
+        }
+        for function in functions {
+            if !calls.contains(&(name.clone(), function.clone())) {
+                calls.push((name.clone(), function));
+            }
+        }
+    }
+    calls
+}
+
+/// Collect the calls to Fluent functions of the resources.
+///
+/// Parsing errors are ignored because they are reported when building
+/// the Fluent entries.
+pub(crate) fn build_fluent_function_calls(
+    fluent_resources: &FluentResources,
+    core_locales_content: &Option<String>,
+) -> FluentFunctionCalls {
+    let parse = |resource_str: &String| {
+        let resource = match FluentResource::try_new(resource_str.to_owned()) {
+            Ok(resource) => resource,
+            Err((resource, _)) => resource,
+        };
+        get_function_calls_from_resource(&resource)
+    };
+    let core_calls =
+        core_locales_content.as_ref().map(parse).unwrap_or_default();
+
+    let mut function_calls: FluentFunctionCalls = HashMap::new();
+    for (lang, resources) in fluent_resources {
+        let calls = function_calls.entry(Rc::clone(lang)).or_default();
+        for resource_str in resources {
+            calls.extend(parse(resource_str));
+        }
+        calls.extend(core_calls.iter().cloned());
+    }
+    function_calls
+}
+
+/// Collect the canonical route segments translated by `route-*` messages of
+/// the resources, sorted and without duplicates.
+///
+/// Parsing errors are ignored because they are reported when building
+/// the Fluent entries.
+pub(crate) fn build_route_segments(
+    fluent_resources: &FluentResources,
+    core_locales_content: &Option<String>,
+) -> Vec<String> {
+    let mut segments = fluent_resources
+        .values()
+        .flatten()
+        .chain(core_locales_content.iter())
+        .flat_map(|resource_str| {
+            let resource =
+                match FluentResource::try_new(resource_str.to_owned()) {
+                    Ok(resource) => resource,
+                    Err((resource, _)) => resource,
+                };
+            resource
+                .entries()
+                .filter_map(|entry| match entry {
+                    fluent_syntax::ast::Entry::Message(msg) => msg
+                        .id
+                        .name
+                        .strip_prefix(ROUTE_MESSAGE_PREFIX)
+                        .filter(|segment| !segment.is_empty())
+                        .map(str::to_string),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    segments.sort();
+    segments.dedup();
+    segments
+}
+
 fn line_col_from_index_content(content: &str, index: usize) -> (usize, usize) {
     let line = content[..index].chars().filter(|c| *c == '\n').count() + 1;
     let col = content[..index]
@@ -444,6 +621,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn attributes() {
+        let fluent_resources = HashMap::from([(
+            Rc::new("en-US".to_string()),
+            vec![concat!(
+                "login-input = Username\n",
+                "    .placeholder = Enter { $name }\n",
+                "    .aria-label = Login\n",
+                "logout =\n",
+                "    .title = Logout\n",
+            )
+            .to_string()],
+        )]);
+        let fluent_file_paths = HashMap::from([(
+            Rc::new("en-US".to_string()),
+            vec!["./locales/en-US/foo.ftl".to_string()],
+        )]);
+        let mut errors = Vec::new();
+        let entries = build_fluent_entries(
+            &fluent_resources,
+            &fluent_file_paths,
+            &manifest_path(),
+            &None,
+            &None,
+            &mut errors,
+        );
+        assert!(errors.is_empty());
+        assert_eq!(
+            entries,
+            HashMap::from([(
+                Rc::new("en-US".to_string()),
+                vec![
+                    FluentEntry {
+                        message_name: "login-input".to_string(),
+                        placeables: vec![]
+                    },
+                    FluentEntry {
+                        message_name: "login-input.placeholder".to_string(),
+                        placeables: vec!["name".to_string()]
+                    },
+                    FluentEntry {
+                        message_name: "login-input.aria-label".to_string(),
+                        placeables: vec![]
+                    },
+                    FluentEntry {
+                        message_name: "logout.title".to_string(),
+                        placeables: vec![]
+                    },
+                ]
+            )])
+        );
+    }
+
     #[test]
     fn empty_resource() {
         let fluent_resources = HashMap::from([(
@@ -779,4 +1009,41 @@ bar = My {not-found} message reference
             )])
         );
     }
+
+    #[test]
+    fn function_calls() {
+        let fluent_resources = HashMap::from([(
+            Rc::new("en-US".to_string()),
+            vec![concat!(
+                "foo = { SHOUT($name) }\n",
+                "bar = { NUMBER($count) ->\n",
+                "    [one] One { UPPER(\"item\") }\n",
+                "   *[other] { NUMBER($count) } items\n",
+                "}\n",
+                "    .title = { LOWER({ SHOUT(\"x\") }) }\n",
+            )
+            .to_string()],
+        )]);
+        let core_locales_content =
+            Some("-brand = { SHOUT(\"acme\") }\n".into());
+
+        let function_calls = build_fluent_function_calls(
+            &fluent_resources,
+            &core_locales_content,
+        );
+        assert_eq!(
+            function_calls,
+            HashMap::from([(
+                Rc::new("en-US".to_string()),
+                vec![
+                    ("foo".to_string(), "SHOUT".to_string()),
+                    ("bar".to_string(), "NUMBER".to_string()),
+                    ("bar".to_string(), "UPPER".to_string()),
+                    ("bar".to_string(), "LOWER".to_string()),
+                    ("bar".to_string(), "SHOUT".to_string()),
+                    ("-brand".to_string(), "SHOUT".to_string()),
+                ]
+            )])
+        );
+    }
 }
@@ -8,8 +8,15 @@ type LangName = String;
 type LangDir = String;
 type LangFlag = Option<String>;
 type LangScript = Option<String>;
-pub(crate) type ParsedLanguage =
-    (LangCode, LangName, LangDir, LangFlag, LangScript);
+type LangFallbacks = Vec<String>;
+pub(crate) type ParsedLanguage = (
+    LangCode,
+    LangName,
+    LangDir,
+    LangFlag,
+    LangScript,
+    LangFallbacks,
+);
 
 #[cfg(any(feature = "json", feature = "yaml", feature = "json5"))]
 #[derive(serde::Deserialize)]
@@ -18,6 +25,13 @@ enum RawLanguagesFileLanguage {
     Basic(String, String),
     CodeNameDir(String, String, String),
     CodeNameDirFlag(String, String, String, String),
+    CodeNameDirFlagFallbacks(
+        String,
+        String,
+        String,
+        Option<String>,
+        Vec<String>,
+    ),
 }
 
 #[cfg(any(feature = "json", feature = "yaml", feature = "json5"))]
@@ -33,13 +47,19 @@ fn fill_languages_file(
                     lang_name,
                     iso639_to_dir(&code_to_iso639(lang_code)),
                     None,
+                    Vec::new(),
                 )),
             RawLanguagesFileLanguage::CodeNameDir(
                 lang_code,
                 lang_name,
                 dir,
-            ) => locales
-                .push(locale_from_lang_code(lang_code, lang_name, dir, None)),
+            ) => locales.push(locale_from_lang_code(
+                lang_code,
+                lang_name,
+                dir,
+                None,
+                Vec::new(),
+            )),
             RawLanguagesFileLanguage::CodeNameDirFlag(
                 lang_code,
                 lang_name,
@@ -50,6 +70,20 @@ fn fill_languages_file(
                 lang_name,
                 dir,
                 Some(flag.to_owned()),
+                Vec::new(),
+            )),
+            RawLanguagesFileLanguage::CodeNameDirFlagFallbacks(
+                lang_code,
+                lang_name,
+                dir,
+                flag,
+                fallbacks,
+            ) => locales.push(locale_from_lang_code(
+                lang_code,
+                lang_name,
+                dir,
+                flag.to_owned(),
+                fallbacks.to_owned(),
             )),
         }
     }
@@ -318,8 +352,16 @@ pub(crate) fn build_languages_quote(
         "[{}]",
         languages
             .iter()
-            .map(|(id, name, dir, flag, script)| {
-                generate_code_for_static_language(id, name, dir, flag, script)
+            .map(|(id, name, dir, flag, script, fallbacks)| {
+                generate_code_for_static_language(
+                    id,
+                    name,
+                    dir,
+                    flag,
+                    script,
+                    fallbacks,
+                    &display_names(id, languages),
+                )
             })
             .collect::<Vec<String>>()
             .join(",")
@@ -334,33 +376,130 @@ fn generate_code_for_static_language(
     dir: &str,
     flag: &Option<String>,
     script: &Option<String>,
+    fallbacks: &[String],
+    display_names: &[(String, String)],
 ) -> String {
-    format!(
-        concat!(
-            "&::leptos_fluent::Language{{",
-            "id:\"{}\",",
-            "name:\"{}\",",
-            "dir:{},",
-            "flag:{},",
-            "script:{}",
-            "}}",
+    let dir = match dir {
+        "ltr" => "&::leptos_fluent::WritingDirection::Ltr",
+        "rtl" => "&::leptos_fluent::WritingDirection::Rtl",
+        _ => "&::leptos_fluent::WritingDirection::Auto",
+    };
+    let mut code = match precomputable_language_identifier(id) {
+        Some(identifier) => format!(
+            concat!(
+                "&::leptos_fluent::Language::with_parsed_identifier(",
+                "\"{}\",",
+                "::leptos_fluent::__reexports::fluent_templates::langid!(\"{}\"),",
+                "\"{}\",",
+                "{})",
+            ),
+            id, identifier, name, dir,
         ),
-        id,
-        name,
-        match dir {
-            "ltr" => "&::leptos_fluent::WritingDirection::Ltr",
-            "rtl" => "&::leptos_fluent::WritingDirection::Rtl",
-            _ => "&::leptos_fluent::WritingDirection::Auto",
-        },
-        match flag {
-            Some(f) => format!("Some(\"{f}\")"),
-            None => "None".to_string(),
-        },
-        match script {
-            Some(s) => format!("Some(\"{s}\")"),
-            None => "None".to_string(),
-        },
-    )
+        None => format!(
+            "&::leptos_fluent::Language::new(\"{id}\",\"{name}\",{dir})"
+        ),
+    };
+    if let Some(flag) = flag {
+        code.push_str(&format!(".with_flag(\"{flag}\")"));
+    }
+    if let Some(script) = script {
+        code.push_str(&format!(".with_script(\"{script}\")"));
+    }
+    if !fallbacks.is_empty() {
+        code.push_str(&format!(
+            ".with_fallbacks(&[{}])",
+            fallbacks
+                .iter()
+                .map(|f| format!("\"{f}\""))
+                .collect::<Vec<String>>()
+                .join(","),
+        ));
+    }
+    if !display_names.is_empty() {
+        code.push_str(&format!(
+            ".with_display_names(&[{}])",
+            display_names
+                .iter()
+                .map(|(id, name)| format!("(\"{id}\",\"{name}\")"))
+                .collect::<Vec<String>>()
+                .join(","),
+        ));
+    }
+    code
+}
+
+/// Names of a language in the other languages, from the CLDR.
+///
+/// Names are capitalized to be displayed in lists and menus. Languages
+/// without data in the CLDR, like pseudolocales, are omitted.
+#[cfg(feature = "displaynames")]
+fn display_names(
+    code: &str,
+    languages: &[ParsedLanguage],
+) -> Vec<(String, String)> {
+    use icu_experimental::displaynames::{
+        multi::{LanguageDisplayNames, LocaleDisplayNamesFormatter},
+        DisplayNamesOptions,
+    };
+    use icu_locale_core::Locale;
+
+    let is_pseudolocale = |code: &str| matches!(code, "en-XA" | "ar-XB");
+
+    let Some(locale) = Some(code)
+        .filter(|code| !is_pseudolocale(code))
+        .and_then(|code| code.parse::<Locale>().ok())
+    else {
+        return Vec::new();
+    };
+    languages
+        .iter()
+        .filter(|(other_code, ..)| other_code != code)
+        .filter_map(|(other_code, ..)| {
+            let other_locale = Some(other_code.as_str())
+                .filter(|code| !is_pseudolocale(code))
+                .and_then(|code| code.parse::<Locale>().ok())?;
+            // the formatter falls back to the codes of the subtags
+            LanguageDisplayNames::try_new(
+                (&other_locale).into(),
+                DisplayNamesOptions::default(),
+            )
+            .ok()?
+            .of(locale.id.language)?;
+            let name = LocaleDisplayNamesFormatter::try_new(
+                (&other_locale).into(),
+                DisplayNamesOptions::default(),
+            )
+            .ok()?
+            .of(&locale)
+            .into_owned();
+            let mut chars = name.chars();
+            let capitalized = chars
+                .next()?
+                .to_uppercase()
+                .chain(chars)
+                .collect::<String>();
+            Some((other_code.clone(), capitalized))
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "displaynames"))]
+fn display_names(
+    _code: &str,
+    _languages: &[ParsedLanguage],
+) -> Vec<(String, String)> {
+    Vec::new()
+}
+
+/// Language identifier that can be built in a constant.
+///
+/// Identifiers with variant subtags need an allocation, so they're parsed
+/// at runtime.
+fn precomputable_language_identifier(id: &str) -> Option<String> {
+    id.parse::<fluent_templates::LanguageIdentifier>()
+        .ok()
+        .filter(|lang_id| lang_id.variants().next().is_none())
+        .map(|lang_id| lang_id.to_string())
 }
 
 fn extract_script_from_lang_code(code: &str) -> Option<String> {
@@ -389,6 +528,7 @@ fn locale_from_lang_code(
     lang_name: &str,
     dir: &str,
     explicit_flag: Option<String>,
+    fallbacks: Vec<String>,
 ) -> ParsedLanguage {
     let script = extract_script_from_lang_code(lang_code);
     let flag = explicit_flag.or_else(|| {
@@ -402,6 +542,7 @@ fn locale_from_lang_code(
         dir.to_owned(),
         flag,
         script,
+        fallbacks,
     )
 }
 
@@ -423,6 +564,7 @@ fn locale_from_parts(
         lang_dir.to_string(),
         flag,
         script_owned,
+        Vec::new(),
     )
 }
 
@@ -516,6 +658,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn static_language_code_includes_fallbacks() {
+        assert_eq!(
+            generate_code_for_static_language(
+                "es-MX",
+                "Español (México)",
+                "ltr",
+                &None,
+                &None,
+                &["es".to_string(), "en".to_string()],
+                &[("en".to_string(), "Mexican Spanish".to_string())],
+            ),
+            concat!(
+                "&::leptos_fluent::Language::with_parsed_identifier(",
+                "\"es-MX\",",
+                "::leptos_fluent::__reexports::fluent_templates::langid!(\"es-MX\"),",
+                "\"Español (México)\",",
+                "&::leptos_fluent::WritingDirection::Ltr)",
+                ".with_fallbacks(&[\"es\",\"en\"])",
+                ".with_display_names(&[(\"en\",\"Mexican Spanish\")])",
+            ),
+        );
+    }
+
+    #[test]
+    fn precomputable_language_identifier_skips_variants() {
+        assert_eq!(
+            precomputable_language_identifier("sr-Latn-RS"),
+            Some("sr-Latn-RS".to_string())
+        );
+        assert_eq!(
+            precomputable_language_identifier("en-us"),
+            Some("en-US".to_string())
+        );
+        assert_eq!(precomputable_language_identifier("ca-valencia"), None);
+        assert_eq!(precomputable_language_identifier("x"), None);
+    }
+
+    #[cfg(feature = "displaynames")]
+    #[test]
+    fn display_names_from_cldr() {
+        let language = |code: &str| {
+            (
+                code.to_string(),
+                String::new(),
+                "ltr".to_string(),
+                None,
+                None,
+                Vec::new(),
+            )
+        };
+        let languages = [
+            language("en"),
+            language("es-ES"),
+            language("zh-Hant"),
+            language("en-XA"),
+        ];
+        assert_eq!(
+            display_names("es-ES", &languages),
+            [
+                ("en".to_string(), "European Spanish".to_string()),
+                ("zh-Hant".to_string(), "西班牙文 (西班牙)".to_string()),
+            ]
+        );
+        assert_eq!(
+            display_names("en", &languages),
+            [
+                ("es-ES".to_string(), "Inglés".to_string()),
+                ("zh-Hant".to_string(), "英文".to_string()),
+            ]
+        );
+        assert!(display_names("en-XA", &languages).is_empty());
+    }
+
     #[test]
     fn country_code_detection_ignores_script() {
         assert_eq!(code_to_country_code("sr-Latn-RS"), Some("RS".to_string()));
@@ -14,6 +14,10 @@ pub(crate) mod fluent_entries;
 mod fluent_resources;
 mod languages;
 mod loader;
+mod messages;
+#[cfg(feature = "ssr")]
+mod request;
+mod static_loader;
 pub(crate) mod tr_macros;
 mod translations_checker;
 mod translations_filler;
@@ -95,15 +99,68 @@ pub(crate) fn debug(msg: &str) {
 ///
 /// See the reference with all the parameters explained in detail at
 /// <https://mondeja.github.io/leptos-fluent/latest/leptos_fluent.html>
-#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
 #[proc_macro]
 pub fn leptos_fluent(
     input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
+    let loader = syn::parse_macro_input!(input as I18nLoader);
+    proc_macro::TokenStream::from(build_i18n_quote(loader, false))
+}
+
+/// Create a handle to translate in the language of the requests on the
+/// server.
+///
+/// Accepts the parameters of [`leptos_fluent!`] and expands to a
+/// `leptos_fluent::server::ServerI18n` with its languages and translations,
+/// that negotiates the language of the requests with its initial language
+/// strategies. Parameters only used by the client, like the ones of the
+/// storages, are ignored.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use leptos_fluent::{server::ServerI18n, server_i18n};
+///
+/// pub fn server_i18n() -> ServerI18n {
+///     server_i18n! {
+///         locales: "./locales",
+///         default_language: "en",
+///         initial_language_from_cookie: true,
+///         initial_language_from_accept_language_header: true,
+///     }
+/// }
+/// ```
+///
+/// See the `leptos_fluent::server` module for how to provide it to server
+/// functions. Requires the `ssr` feature.
+#[proc_macro]
+pub fn server_i18n(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    if !cfg!(feature = "ssr") {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "The server_i18n! macro requires the feature 'ssr' enabled.",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let loader = syn::parse_macro_input!(input as I18nLoader);
+    proc_macro::TokenStream::from(build_i18n_quote(loader, true))
+}
+
+/// Expand the parameters of `leptos_fluent!` to the i18n context provider,
+/// or to the `ServerI18n` handle of `server_i18n!` with `server_i18n`.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+fn build_i18n_quote(
+    loader: I18nLoader,
+    server_i18n: bool,
+) -> proc_macro2::TokenStream {
     let I18nLoader {
         fluent_file_paths,
+        route_segments,
         children,
         translations,
+        lazy_translations,
         languages,
         languages_path,
         raw_languages_path,
@@ -113,9 +170,17 @@ pub fn leptos_fluent(
         check_translations,
         fill_translations,
         customise,
+        functions,
+        pseudolocales,
+        negotiation_strategy,
+        namespace,
+        on_error,
+        use_isolating,
         provide_meta_context,
         sync_html_tag_lang,
         sync_html_tag_dir,
+        sync_hreflang_links,
+        hreflang_origin,
         initial_language_from_url_param,
         url_param,
         initial_language_from_url_param_to_local_storage,
@@ -159,6 +224,7 @@ pub fn leptos_fluent(
         initial_language_from_url_path_to_local_storage,
         initial_language_from_url_path_to_session_storage,
         initial_language_from_url_path_to_server_function,
+        set_language_to_url_path,
         #[cfg(feature = "system")]
         initial_language_from_system,
         #[cfg(feature = "system")]
@@ -169,7 +235,7 @@ pub fn leptos_fluent(
         initial_language_from_data_file,
         #[cfg(feature = "system")]
         data_file_key,
-    } = syn::parse_macro_input!(input as I18nLoader);
+    } = loader;
 
     let n_languages = languages.len();
     let languages_quote = build_languages_quote(&languages);
@@ -181,25 +247,35 @@ pub fn leptos_fluent(
         &core_locales_path,
     );
 
+    // Namespaced contexts don't replace the `I18n` context
+    let i18n_context_quote = match namespace {
+        Some(ref namespace) => quote! {
+            ::leptos_fluent::expect_i18n_namespace(#namespace)
+        },
+        None => quote! {
+            ::leptos::prelude::expect_context::<::leptos_fluent::I18n>()
+        },
+    };
+
     // Less code possible on nightly
     #[cfg(feature = "nightly")]
     let get_language_quote = quote! {
-        (::leptos::prelude::expect_context::<::leptos_fluent::I18n>())()
+        (#i18n_context_quote)()
     };
 
     #[cfg(all(feature = "nightly", not(feature = "ssr")))]
     let set_language_quote = quote! {
-        (::leptos::prelude::expect_context::<::leptos_fluent::I18n>())(l)
+        (#i18n_context_quote)(l)
     };
 
     #[cfg(not(feature = "nightly"))]
     let get_language_quote = quote! {
-        ::leptos::prelude::expect_context::<::leptos_fluent::I18n>().language.get()
+        #i18n_context_quote.language.get()
     };
 
     #[cfg(all(not(feature = "nightly"), not(feature = "ssr")))]
     let set_language_quote = quote! {
-        ::leptos::prelude::expect_context::<::leptos_fluent::I18n>().language.set(l)
+        #i18n_context_quote.language.set(l)
     };
 
     let cookie_name_quote = match cookie_name.lit {
@@ -276,7 +352,7 @@ pub fn leptos_fluent(
 
         let effect_quote = quote! {
             if let Ok(l) = ::leptos_fluent::__reexports::current_locale::current_locale() {
-                lang = ::leptos_fluent::l(&l, &LANGUAGES);
+                lang = ::leptos_fluent::l_with_strategy(&l, &LANGUAGES, NEGOTIATION_STRATEGY);
                 if let Some(l) = lang {
                     #initial_language_from_system_to_data_file_quote
                 }
@@ -381,7 +457,7 @@ pub fn leptos_fluent(
                     if let Some(l) = ::leptos_fluent::data_file::get(
                         #initial_language_from_data_file_quote
                     ) {
-                        lang = ::leptos_fluent::l(&l, &LANGUAGES);
+                        lang = ::leptos_fluent::l_with_strategy(&l, &LANGUAGES, NEGOTIATION_STRATEGY);
                     }
                 };
 
@@ -475,7 +551,7 @@ pub fn leptos_fluent(
                         let lang_result = #ident().await;
                         if let Ok(maybe_lang) = lang_result {
                             if let Some(l) = maybe_lang {
-                                lang = ::leptos_fluent::l(&l, &LANGUAGES);
+                                lang = ::leptos_fluent::l_with_strategy(&l, &LANGUAGES, NEGOTIATION_STRATEGY);
                                 if let Some(l) = lang {
                                     #set_to_cookie_quote
                                     #set_to_local_storage_quote
@@ -627,7 +703,7 @@ pub fn leptos_fluent(
 
                     quote! {
                         if let Some(url_path) = ::leptos_fluent::url::path::get() {
-                            lang = ::leptos_fluent::l(#ident(&url_path), &LANGUAGES);
+                            lang = ::leptos_fluent::l_with_strategy(#ident(&url_path), &LANGUAGES, NEGOTIATION_STRATEGY);
                             if let Some(l) = lang {
                                 #to_cookie_effect_quote
                                 #to_local_storage_effect_quote
@@ -638,31 +714,13 @@ pub fn leptos_fluent(
                     }
                 };
 
-                #[cfg(all(feature = "ssr", feature = "actix"))]
-                let effect_quote = quote! {
-                    if let Some(req) = ::leptos::prelude::use_context::<::leptos_actix::Request>() {
-                        lang = ::leptos_fluent::l(#ident(&req.path()), &LANGUAGES);
-                        if let Some(l) = lang {
-                            #initial_language_from_url_path_to_server_function_quote
-                        }
+                #[cfg(feature = "ssr")]
+                let effect_quote = request::with_request_quote(&quote! {
+                    lang = ::leptos_fluent::l_with_strategy(#ident(req.path()), &LANGUAGES, NEGOTIATION_STRATEGY);
+                    if let Some(l) = lang {
+                        #initial_language_from_url_path_to_server_function_quote
                     }
-                };
-
-                #[cfg(all(feature = "ssr", feature = "axum"))]
-                let effect_quote = quote! {
-                    if let Some(req) = ::leptos::prelude::use_context::<::axum::http::request::Parts>() {
-                        lang = ::leptos_fluent::l(#ident(&req.uri.path()), &LANGUAGES);
-                        if let Some(l) = lang {
-                            #initial_language_from_url_path_to_server_function_quote
-                        }
-                    }
-                };
-
-                #[cfg(all(feature = "ssr", not(feature = "axum"), not(feature = "actix")))]
-                let effect_quote = {
-                    _ = initial_language_from_url_path_to_server_function_quote;
-                    quote! {}
-                };
+                });
 
                 let quote = quote! {
                     if lang.is_none() {
@@ -762,6 +820,90 @@ pub fn leptos_fluent(
         },
     };
 
+    let sync_hreflang_links_quote: proc_macro2::TokenStream = {
+        let sync_hreflang_links_bool_quote: proc_macro2::TokenStream = {
+            let quote = sync_hreflang_links
+                .iter()
+                .map(|param| match param.expr {
+                    Some(ref expr) => {
+                        let q = quote! { #expr };
+                        match param.exprpath {
+                            Some(ref path) => quote!(#path{#q}),
+                            None => q,
+                        }
+                    }
+                    None => quote! { false },
+                })
+                .collect::<proc_macro2::TokenStream>();
+
+            match quote.is_empty() {
+                true => quote! { false },
+                false => quote! { #quote },
+            }
+        };
+
+        #[cfg(feature = "ssr")]
+        let path_quote = {
+            let set_path_quote = request::with_request_quote(
+                &quote! { path = Some(req.path_and_query()); },
+            );
+            quote! {
+                path={
+                    let mut path = None;
+                    #set_path_quote
+                    path
+                }
+            }
+        };
+
+        #[cfg(not(feature = "ssr"))]
+        let path_quote = quote! {};
+
+        let url_path_quote = match url_path.is_some() {
+            true => quote! { url_path=true },
+            false => quote! {},
+        };
+
+        let origin_quote = match hreflang_origin.lit {
+            Some(ref lit) => quote! { origin=#lit },
+            None => match hreflang_origin.expr {
+                Some(ref expr) => quote! { origin={#expr} },
+                None => quote! {},
+            },
+        };
+
+        let hreflang_links_quote = quote! {
+            <HreflangLinks
+                #origin_quote
+                url_param=#url_param_quote
+                #url_path_quote
+                #path_quote
+            />
+        };
+
+        // The links need the `I18n` of the application in the context,
+        // which is not provided directly by namespaced loaders.
+        let hreflang_links_quote = match namespace {
+            Some(_) => quote! {
+                <Provider value=i18n>
+                    #hreflang_links_quote
+                </Provider>
+            },
+            None => hreflang_links_quote,
+        };
+
+        match sync_hreflang_links_bool_quote.to_string() == "false" {
+            true => quote! {},
+            false => quote! {
+                {({#sync_hreflang_links_bool_quote}).then(|| {
+                    use ::leptos::context::Provider;
+                    use ::leptos_fluent::components::HreflangLinks;
+                    ::leptos::prelude::view! { #hreflang_links_quote }
+                })}
+            },
+        }
+    };
+
     #[cfg(not(feature = "ssr"))]
     let sync_language_with_local_storage_quote: proc_macro2::TokenStream = {
         let effect_quote = quote! {
@@ -931,7 +1073,7 @@ pub fn leptos_fluent(
         #[cfg(not(feature = "ssr"))]
         let parse_language_quote = quote! {
             if let Some(l) = ::leptos_fluent::url::param::get(#url_param_quote) {
-                lang = ::leptos_fluent::l(&l, &LANGUAGES);
+                lang = ::leptos_fluent::l_with_strategy(&l, &LANGUAGES, NEGOTIATION_STRATEGY);
                 if let Some(l) = lang {
                     #set_to_local_storage_quote
                     #set_to_session_storage_quote
@@ -941,68 +1083,30 @@ pub fn leptos_fluent(
             }
         };
 
-        #[cfg(all(feature = "ssr", any(feature = "actix", feature = "axum")))]
-        let lang_parser_quote = quote! {
-            let mut maybe_lang = None;
-            for (key, value) in uri_query.split('&').map(|pair| {
-                let mut split = pair.splitn(2, '=');
-                (split.next().unwrap_or(""), split.next().unwrap_or(""))
-            }) {
-                if key == #url_param_quote {
-                    maybe_lang = Some(value);
-                    break;
-                }
-            }
-
-            if let Some(l) = maybe_lang {
-                lang = ::leptos_fluent::l(&l, &LANGUAGES);
+        #[cfg(feature = "ssr")]
+        let parse_language_quote = request::with_request_quote(&quote! {
+            if let Some(l) = req.url_param(#url_param_quote) {
+                lang = ::leptos_fluent::l_with_strategy(l, &LANGUAGES, NEGOTIATION_STRATEGY);
                 if let Some(l) = lang {
                     #set_to_server_function_quote
                 }
             }
-        };
-
-        #[cfg(all(feature = "ssr", feature = "actix"))]
-        let parse_language_quote = quote! {
-            if let Some(req) = ::leptos::prelude::use_context::<::leptos_actix::Request>() {
-                let uri_query = req.uri().query().unwrap_or("");
-                #lang_parser_quote
-            }
-        };
-
-        #[cfg(all(feature = "ssr", feature = "axum"))]
-        let parse_language_quote = quote! {
-            if let Some(req) = ::leptos::prelude::use_context::<::axum::http::request::Parts>() {
-                let uri_query = req.uri.query().unwrap_or("");
-                #lang_parser_quote
-            }
-        };
-
-        // Other SSR framework or the user is not using any
-        #[cfg(all(
-            feature = "ssr",
-            not(feature = "actix"),
-            not(feature = "axum"),
-        ))]
-        let parse_language_quote = quote! {};
+        });
 
         initial_language_from_url_param
             .iter()
             .map(|param| match param.expr {
-                Some(ref expr) => match parse_language_quote.is_empty() {
-                    true => quote!(),
-                    false => {
-                        let q = quote! {
-                            if #expr {
-                                #parse_language_quote
-                            }
-                        };
-                        match param.exprpath {
-                            Some(ref path) => quote!(#path{#q}),
-                            None => q,
+                Some(ref expr) => {
+                    let q = quote! {
+                        if #expr {
+                            #parse_language_quote
                         }
+                    };
+                    match param.exprpath {
+                        Some(ref path) => quote!(#path{#q}),
+                        None => q,
                     }
-                },
+                }
                 None => quote!(),
             })
             .collect()
@@ -1082,7 +1186,7 @@ pub fn leptos_fluent(
         let local_storage_get_quote = quote! {
             if let Some(l) = ::leptos_fluent::local_storage::get(#local_storage_key_quote)
             {
-                lang = ::leptos_fluent::l(&l, &LANGUAGES);
+                lang = ::leptos_fluent::l_with_strategy(&l, &LANGUAGES, NEGOTIATION_STRATEGY);
                 if let Some(l) = lang {
                     #initial_language_from_local_storage_to_cookie_quote
                     #initial_language_from_local_storage_to_session_storage_quote
@@ -1184,7 +1288,7 @@ pub fn leptos_fluent(
         let session_storage_get_quote = quote! {
             if let Some(l) = ::leptos_fluent::session_storage::get(#session_storage_key_quote)
             {
-                lang = ::leptos_fluent::l(&l, &LANGUAGES);
+                lang = ::leptos_fluent::l_with_strategy(&l, &LANGUAGES, NEGOTIATION_STRATEGY);
                 if let Some(l) = lang {
                     #initial_language_from_session_storage_to_cookie_quote
                     #initial_language_from_session_storage_to_local_storage_quote
@@ -1253,6 +1357,64 @@ pub fn leptos_fluent(
             .collect()
     };
 
+    let sync_language_with_url_path_quote: proc_macro2::TokenStream = {
+        #[cfg(not(feature = "ssr"))]
+        let effect_quote = quote! {
+            ::leptos::prelude::Effect::new(move |_| {
+                ::leptos_fluent::url::path::set(&#i18n_context_quote);
+            });
+        };
+
+        // Requests to paths without language are redirected to the path
+        // with the initial language.
+        #[cfg(all(feature = "ssr", any(feature = "actix", feature = "axum")))]
+        let effect_quote = {
+            #[cfg(feature = "actix")]
+            let redirect_quote = quote! { ::leptos_actix::redirect };
+            #[cfg(feature = "axum")]
+            let redirect_quote = quote! { ::leptos_axum::redirect };
+
+            request::with_request_quote(&quote! {
+                if !::leptos_fluent::url::path::is_localized(req.path(), &LANGUAGES) {
+                    #redirect_quote(&::leptos_fluent::url::path::localize_href(
+                        &i18n,
+                        Some(i18n.language.get_untracked()),
+                        &req.path_and_query(),
+                    ));
+                }
+            })
+        };
+
+        // Redirections need the response of the framework
+        #[cfg(all(
+            feature = "ssr",
+            not(feature = "actix"),
+            not(feature = "axum"),
+        ))]
+        let effect_quote = quote! {};
+
+        match effect_quote.is_empty() {
+            true => quote! {},
+            false => set_language_to_url_path
+                .iter()
+                .map(|param| match param.expr {
+                    Some(ref expr) => {
+                        let q = quote! {
+                            if #expr {
+                                #effect_quote
+                            }
+                        };
+                        match param.exprpath {
+                            Some(ref path) => quote!(#path{#q}),
+                            None => q,
+                        }
+                    }
+                    None => quote!(),
+                })
+                .collect(),
+        }
+    };
+
     #[cfg(not(feature = "ssr"))]
     let initial_language_from_navigator_quote: proc_macro2::TokenStream = {
         let initial_language_from_navigator_to_local_storage_quote: proc_macro2::TokenStream = {
@@ -1347,7 +1509,7 @@ pub fn leptos_fluent(
                 if language.is_none() {
                     continue;
                 }
-                lang = ::leptos_fluent::l(&language.unwrap(), &LANGUAGES);
+                lang = ::leptos_fluent::l_with_strategy(&language.unwrap(), &LANGUAGES, NEGOTIATION_STRATEGY);
                 if let Some(l) = lang {
                     #initial_language_from_navigator_to_local_storage_quote
                     #initial_language_from_navigator_to_session_storage_quote
@@ -1406,7 +1568,7 @@ pub fn leptos_fluent(
                             if language.is_none() {
                                 continue;
                             }
-                            let l = ::leptos_fluent::l(&language.unwrap(), &LANGUAGES);
+                            let l = ::leptos_fluent::l_with_strategy(&language.unwrap(), &LANGUAGES, NEGOTIATION_STRATEGY);
                             #log_language_quote
                             if let Some(l) = l {
                                 #set_language_quote;
@@ -1448,67 +1610,16 @@ pub fn leptos_fluent(
     };
 
     // Accept-Language header
-    //   Actix
-    #[cfg(all(feature = "actix", feature = "ssr"))]
-    let initial_language_from_accept_language_header_quote: proc_macro2::TokenStream = {
-        let effect_quote = quote! {
-            if let Some(req) = ::leptos::prelude::use_context::<::leptos_actix::Request>() {
-                let maybe_header = req
-                    .headers()
-                    .get(::actix_web::http::header::ACCEPT_LANGUAGE)
-                    .and_then(|header| header.to_str().ok());
-
-                if let Some(header) = maybe_header {
-                    let langs = ::leptos_fluent::http_header::parse(header);
-                    for l in langs {
-                        if let Some(l) = ::leptos_fluent::l(&l, &LANGUAGES) {
-                            lang = Some(l);
-                            break;
-                        }
-                    }
-                }
-            }
-        };
-
-        initial_language_from_accept_language_header.iter().map(|param| {
-            match param.expr {
-                Some(ref expr) => {
-                    let q = quote! {
-                        if #expr && lang.is_none() {
-                            #effect_quote
-                        }
-                    };
-                    match param.exprpath {
-                        Some(ref path) => quote!(#path{#q}),
-                        None => q,
-                    }
-                },
-                None => quote!(),
-            }
-        }).collect()
-    };
-
-    //   Axum
-    #[cfg(all(feature = "axum", feature = "ssr"))]
+    #[cfg(feature = "ssr")]
     let initial_language_from_accept_language_header_quote: proc_macro2::TokenStream = {
-        let effect_quote = quote! {
-            if let Some(req) = ::leptos::prelude::use_context::<::axum::http::request::Parts>() {
-                let maybe_header = req
-                    .headers
-                    .get(::axum::http::header::ACCEPT_LANGUAGE)
-                    .and_then(|header| header.to_str().ok());
-
-                if let Some(header) = maybe_header {
-                    let langs = ::leptos_fluent::http_header::parse(header);
-                    for l in langs {
-                        if let Some(l) = ::leptos_fluent::l(&l, &LANGUAGES) {
-                            lang = Some(l);
-                            break;
-                        }
-                    }
+        let effect_quote = request::with_request_quote(&quote! {
+            for l in req.accept_languages() {
+                if let Some(l) = ::leptos_fluent::l_with_strategy(&l, &LANGUAGES, NEGOTIATION_STRATEGY) {
+                    lang = Some(l);
+                    break;
                 }
             }
-        };
+        });
 
         initial_language_from_accept_language_header.iter().map(|param| {
             match param.expr {
@@ -1528,10 +1639,6 @@ pub fn leptos_fluent(
         }).collect()
     };
 
-    //   Other SSR framework or the user is not using any
-    #[cfg(all(not(feature = "actix"), not(feature = "axum"), feature = "ssr"))]
-    let initial_language_from_accept_language_header_quote = quote! {};
-
     #[cfg(not(feature = "ssr"))]
     {
         _ = initial_language_from_accept_language_header;
@@ -1604,7 +1711,7 @@ pub fn leptos_fluent(
 
         let parse_client_cookie_quote = quote! {
             if let Some(cookie) = ::leptos_fluent::cookie::get(#cookie_name_quote) {
-                if let Some(l) = ::leptos_fluent::l(&cookie, &LANGUAGES) {
+                if let Some(l) = ::leptos_fluent::l_with_strategy(&cookie, &LANGUAGES, NEGOTIATION_STRATEGY) {
                     lang = Some(l);
                     #initial_language_from_cookie_to_local_storage_quote
                     #initial_language_from_cookie_to_session_storage_quote
@@ -1672,69 +1779,16 @@ pub fn leptos_fluent(
         _ = set_language_to_cookie;
     }
 
-    //   Actix
-    #[cfg(all(feature = "ssr", feature = "actix"))]
-    let initial_language_from_cookie_quote: proc_macro2::TokenStream = {
-        let effect_quote = quote! {
-            if let Some(req) = ::leptos::prelude::use_context::<::leptos_actix::Request>() {
-                let maybe_cookie = req
-                    .cookie(#cookie_name_quote)
-                    .and_then(|cookie| Some(cookie.value().to_string()));
-
-                if let Some(cookie) = maybe_cookie {
-                    if let Some(l) = ::leptos_fluent::l(&cookie, &LANGUAGES) {
-                        lang = Some(l);
-                        #initial_language_from_cookie_to_server_function_quote
-                    }
-                }
-            }
-        };
-
-        initial_language_from_cookie
-            .iter()
-            .map(|param| match param.expr {
-                Some(ref expr) => {
-                    let q = quote! {
-                        if #expr && lang.is_none() {
-                            #effect_quote
-                        }
-                    };
-                    match param.exprpath {
-                        Some(ref path) => quote!(#path{#q}),
-                        None => q,
-                    }
-                }
-                None => quote!(),
-            })
-            .collect()
-    };
-
-    //     TODO: Set in Set-Cookie header?
-    #[cfg(all(feature = "ssr", feature = "actix"))]
-    let sync_language_with_cookie_quote = quote! {};
-
-    //   Axum
-    #[cfg(all(feature = "ssr", feature = "axum"))]
+    #[cfg(feature = "ssr")]
     let initial_language_from_cookie_quote: proc_macro2::TokenStream = {
-        let effect_quote = quote! {
-            if let Some(req) = ::leptos::prelude::use_context::<::axum::http::request::Parts>() {
-                let maybe_cookie = req
-                    .headers
-                    .get(::axum::http::header::COOKIE)
-                    .and_then(|header| header.to_str().ok())
-                    .and_then(|cookie| {
-                        let cookie = cookie.split(';').find(|c| c.trim_start().starts_with(#cookie_name_quote));
-                        cookie.map(|c| c.split('=').nth(1).unwrap().trim_start().to_string())
-                    });
-
-                if let Some(cookie) = maybe_cookie {
-                    if let Some(l) = ::leptos_fluent::l(&cookie, &LANGUAGES) {
-                        lang = Some(l);
-                        #initial_language_from_cookie_to_server_function_quote
-                    }
+        let effect_quote = request::with_request_quote(&quote! {
+            if let Some(cookie) = req.cookie(#cookie_name_quote) {
+                if let Some(l) = ::leptos_fluent::l_with_strategy(&cookie, &LANGUAGES, NEGOTIATION_STRATEGY) {
+                    lang = Some(l);
+                    #initial_language_from_cookie_to_server_function_quote
                 }
             }
-        };
+        });
 
         initial_language_from_cookie
             .iter()
@@ -1756,22 +1810,7 @@ pub fn leptos_fluent(
     };
 
     //     TODO: Set in Set-Cookie header?
-    #[cfg(all(feature = "ssr", feature = "axum"))]
-    let sync_language_with_cookie_quote = quote! {};
-
-    //   Other SSR frameworks or the user is not using any
-    #[cfg(all(not(feature = "actix"), not(feature = "axum"), feature = "ssr"))]
-    let initial_language_from_cookie_quote = quote! {};
-    #[cfg(all(
-        not(feature = "actix"),
-        not(feature = "axum"),
-        feature = "ssr"
-    ))]
-    {
-        _ = initial_language_from_cookie_to_server_function;
-    };
-
-    #[cfg(all(not(feature = "actix"), not(feature = "axum"), feature = "ssr"))]
+    #[cfg(feature = "ssr")]
     let sync_language_with_cookie_quote = quote! {};
 
     let initial_language_quote = {
@@ -1892,6 +1931,15 @@ pub fn leptos_fluent(
                         lit_bool_expr_or_idents(&sync_html_tag_lang);
                     let sync_html_tag_dir_quote =
                         lit_bool_expr_or_idents(&sync_html_tag_dir);
+                    let sync_hreflang_links_quote =
+                        lit_bool_expr_or_idents(&sync_hreflang_links);
+                    let hreflang_origin_quote = match hreflang_origin.lit {
+                        Some(ref lit) => quote! { Some(#lit) },
+                        None => match hreflang_origin.expr {
+                            Some(ref expr) => quote! { Some(#expr) },
+                            None => quote! { None },
+                        },
+                    };
                     let url_param_quote =
                         litstr_or_default(&url_param.lit, &url_param.expr, "lang");
                     let initial_language_from_url_param_quote =
@@ -2030,6 +2078,8 @@ pub fn leptos_fluent(
                         lit_bool_expr_or_idents(
                             &initial_language_from_url_path_to_server_function,
                         );
+                    let set_language_to_url_path_quote =
+                        lit_bool_expr_or_idents(&set_language_to_url_path);
 
                     let system_quote = {
                         #[cfg(not(feature = "system"))]
@@ -2074,6 +2124,8 @@ pub fn leptos_fluent(
                             fill_translations: #fill_translations_quote,
                             sync_html_tag_lang: #sync_html_tag_lang_quote,
                             sync_html_tag_dir: #sync_html_tag_dir_quote,
+                            sync_hreflang_links: #sync_hreflang_links_quote,
+                            hreflang_origin: #hreflang_origin_quote,
                             url_param: #url_param_quote,
                             initial_language_from_url_param: #initial_language_from_url_param_quote,
                             initial_language_from_url_param_to_local_storage: #initial_language_from_url_param_to_local_storage,
@@ -2117,6 +2169,7 @@ pub fn leptos_fluent(
                             initial_language_from_url_path_to_local_storage: #initial_language_from_url_path_to_local_storage_quote,
                             initial_language_from_url_path_to_session_storage: #initial_language_from_url_path_to_session_storage_quote,
                             initial_language_from_url_path_to_server_function: #initial_language_from_url_path_to_server_function_quote,
+                            set_language_to_url_path: #set_language_to_url_path_quote,
                             provide_meta_context: true,
                             #system_quote
                         };
@@ -2133,16 +2186,87 @@ pub fn leptos_fluent(
         }).collect()
     };
 
+    // Content-Language and Vary response headers
+    #[cfg(all(feature = "ssr", any(feature = "actix", feature = "axum")))]
+    let response_headers_quote: proc_macro2::TokenStream = {
+        let vary_quote: proc_macro2::TokenStream = [
+            (
+                &initial_language_from_accept_language_header,
+                "Accept-Language",
+            ),
+            (&initial_language_from_cookie, "Cookie"),
+        ]
+        .into_iter()
+        .flat_map(|(params, header)| {
+            params.iter().map(move |param| match param.expr {
+                Some(ref expr) => {
+                    let q = quote! {
+                        if #expr && !vary.contains(&#header) {
+                            vary.push(#header);
+                        }
+                    };
+                    match param.exprpath {
+                        Some(ref path) => quote!(#path{#q}),
+                        None => q,
+                    }
+                }
+                None => quote!(),
+            })
+        })
+        .collect();
+
+        #[cfg(feature = "actix")]
+        let (response_quote, header_quote) = (
+            quote!(::leptos_actix::ResponseOptions),
+            quote!(::actix_web::http::header),
+        );
+        #[cfg(feature = "axum")]
+        let (response_quote, header_quote) = (
+            quote!(::leptos_axum::ResponseOptions),
+            quote!(::axum::http::header),
+        );
+
+        match namespace {
+            Some(_) => quote!(),
+            None => quote! {
+                if let Some(response) = ::leptos::prelude::use_context::<#response_quote>() {
+                    let language = ::leptos::prelude::GetUntracked::get_untracked(&i18n.language);
+                    response.insert_header(
+                        #header_quote::CONTENT_LANGUAGE,
+                        #header_quote::HeaderValue::from_static(language.id),
+                    );
+                    let mut vary: Vec<&str> = Vec::new();
+                    #vary_quote
+                    if !vary.is_empty() {
+                        response.append_header(
+                            #header_quote::VARY,
+                            #header_quote::HeaderValue::from_str(&vary.join(", ")).unwrap(),
+                        );
+                    }
+                }
+            },
+        }
+    };
+
+    // Other SSR framework, the user is not using any or client side
+    #[cfg(not(all(
+        feature = "ssr",
+        any(feature = "actix", feature = "axum")
+    )))]
+    let response_headers_quote = quote!();
+
     let other_quotes = quote! {
         #sync_language_with_server_function_quote
         #sync_language_with_session_storage_quote
         #sync_language_with_local_storage_quote
         #sync_language_with_url_param_quote
+        #sync_language_with_url_path_quote
         #sync_language_with_cookie_quote
         #sync_language_with_data_file_quote
         #set_language_from_navigator_quote
         #files_tracker_quote
         #leptos_fluent_provide_meta_context_quote
+        #response_headers_quote
     };
 
     let initial_language_index = match default_language {
@@ -2150,39 +2274,292 @@ pub fn leptos_fluent(
         None => 0,
     };
 
+    // the handle of `server_i18n!` embeds the translations of all the
+    // languages to render the language of each request
+    let lazy_translations = lazy_translations.filter(|_| !server_i18n);
+
     let (fluent_templates_quote, translations_quote) = match translations {
         Some(ref translations) => (quote!(), quote!(#translations)),
+        None if lazy_translations.is_some() => (quote!(), quote!(Vec::new())),
         None => {
             let fallback_language =
                 &languages[initial_language_index].0.to_string();
 
-            let core_locales_quote = match &core_locales_path {
-                Some(ref path) => quote!(core_locales: #path,),
-                None => quote!(),
+            let core_resource_quote = match &core_locales_path {
+                Some(ref path) => quote! {
+                    Some(
+                        fluent_templates::fs::resource_from_str(include_str!(#path))
+                            .expect("Couldn't load core resources"),
+                    )
+                },
+                None => quote!(None),
             };
 
-            let customise_quote =
-                customise.map_or(quote!(), |c| quote! { customise: #c, });
+            let mut locales = fluent_file_paths.iter().collect::<Vec<_>>();
+            locales.sort();
+            let resources_quote = locales.into_iter().map(|(lang, paths)| {
+                let lang = lang.as_str();
+                let mut paths = paths.clone();
+                paths.sort();
+                quote! {
+                    (
+                        #lang.parse().unwrap(),
+                        vec![#(
+                            fluent_templates::fs::resource_from_str(include_str!(#paths)).unwrap()
+                        ),*],
+                    )
+                }
+            });
 
+            let customise_quote = match customise {
+                Some(c) => quote! {
+                    let customise: &dyn Fn(
+                        &mut fluent_templates::FluentBundle<
+                            &'static fluent_templates::fluent_bundle::FluentResource,
+                        >,
+                    ) = &#c;
+                    customise(bundle);
+                },
+                None => quote!(),
+            };
+
+            // the loader is built like `static_loader!` does, but keeping
+            // its bundles to format the messages directly with them
             (
                 quote! {
-                    use ::leptos_fluent::__reexports::fluent_templates::{static_loader, self};
-                    static_loader! {
-                        static TRS = {
-                            locales: #locales_path,
-                            fallback_language: #fallback_language,
-                            #core_locales_quote
-                            #customise_quote
-                        };
+                    use ::leptos_fluent::__reexports::fluent_templates;
+                    static RESOURCES: ::std::sync::LazyLock<
+                        ::std::collections::HashMap<
+                            fluent_templates::LanguageIdentifier,
+                            Vec<fluent_templates::fluent_bundle::FluentResource>,
+                        >,
+                    > = ::std::sync::LazyLock::new(|| {
+                        ::std::collections::HashMap::from([#(#resources_quote),*])
+                    });
+                    static CORE_RESOURCE: ::std::sync::LazyLock<
+                        Option<fluent_templates::fluent_bundle::FluentResource>,
+                    > = ::std::sync::LazyLock::new(|| #core_resource_quote);
+                    fn customise(
+                        bundle: &mut fluent_templates::FluentBundle<
+                            &'static fluent_templates::fluent_bundle::FluentResource,
+                        >,
+                    ) {
+                        ::leptos_fluent::functions::add_functions_with(bundle, FUNCTIONS);
+                        #customise_quote
                     }
+                    static BUNDLES: ::std::sync::LazyLock<::leptos_fluent::StaticBundles> =
+                        ::std::sync::LazyLock::new(|| {
+                            fluent_templates::loader::build_bundles(
+                                &RESOURCES,
+                                CORE_RESOURCE.as_ref(),
+                                customise,
+                            )
+                        });
+                    // used when placeables are not isolated
+                    static UNISOLATED_BUNDLES: ::std::sync::LazyLock<::leptos_fluent::StaticBundles> =
+                        ::std::sync::LazyLock::new(|| {
+                            fluent_templates::loader::build_bundles(
+                                &RESOURCES,
+                                CORE_RESOURCE.as_ref(),
+                                |bundle| {
+                                    customise(bundle);
+                                    bundle.set_use_isolating(false);
+                                },
+                            )
+                        });
+                    static FALLBACKS: ::std::sync::LazyLock<
+                        ::std::collections::HashMap<
+                            fluent_templates::LanguageIdentifier,
+                            Vec<fluent_templates::LanguageIdentifier>,
+                        >,
+                    > = ::std::sync::LazyLock::new(|| {
+                        fluent_templates::loader::build_fallbacks(
+                            &RESOURCES.keys().cloned().collect::<Vec<_>>(),
+                        )
+                    });
+                    static TRS: ::std::sync::LazyLock<fluent_templates::StaticLoader> =
+                        ::std::sync::LazyLock::new(|| {
+                            fluent_templates::StaticLoader::new(
+                                &BUNDLES,
+                                &FALLBACKS,
+                                #fallback_language.parse().unwrap(),
+                            )
+                        });
                 },
                 quote!(vec![&TRS]),
             )
         }
     };
 
+    let static_bundles_quote = match translations {
+        Some(ref translations) => translations
+            .loaders()
+            .iter()
+            .map(|loader| quote!(i18n.add_loader(&#loader);))
+            .collect(),
+        None if lazy_translations.is_none() => {
+            quote!(i18n.add_static_bundles(&TRS, &BUNDLES, &UNISOLATED_BUNDLES);)
+        }
+        None => quote!(),
+    };
+
+    let route_segments_quote = match route_segments.is_empty() {
+        true => quote!(),
+        false => quote!(i18n.set_route_segments(&[#(#route_segments),*]);),
+    };
+
+    let lazy_translations_quote = match lazy_translations {
+        Some(loader::LazyTranslations { url, files }) => {
+            let fallback_language = &languages[initial_language_index].0;
+            let core_quote = match &core_locales_path {
+                Some(path) => quote!(Some(include_str!(#path))),
+                None => quote!(None),
+            };
+
+            let mut embedded_paths = fluent_file_paths
+                .get(fallback_language)
+                .cloned()
+                .unwrap_or_default();
+            embedded_paths.sort();
+            let embedded_quote: proc_macro2::TokenStream = core_locales_path
+                .iter()
+                .chain(embedded_paths.iter())
+                .map(|path| {
+                    let message = format!("Invalid Fluent resource {path}");
+                    quote! {
+                        i18n.add_resource(
+                            #fallback_language,
+                            include_str!(#path).to_string(),
+                        ).expect(#message);
+                    }
+                })
+                .collect();
+
+            let files_quote = files
+                .iter()
+                .map(|(lang, paths)| quote!((#lang, &[#(#paths),*])));
+
+            quote! {
+                #embedded_quote
+                i18n.set_lazy_translations(::leptos_fluent::LazyTranslations {
+                    url: #url,
+                    fallback_language: #fallback_language,
+                    files: &[#(#files_quote),*],
+                    core: #core_quote,
+                });
+            }
+        }
+        None => quote!(),
+    };
+
+    let pseudolocales_quote = match pseudolocales.is_empty() {
+        true => quote!(),
+        false => {
+            let default_language = &languages[initial_language_index].0;
+            let mut paths = fluent_file_paths
+                .get(default_language)
+                .cloned()
+                .unwrap_or_default();
+            paths.sort();
+            let resources_quote = core_locales_path
+                .iter()
+                .chain(paths.iter())
+                .map(|path| {
+                    let message = format!("Invalid Fluent resource {path}");
+                    quote! {
+                        ::std::sync::Arc::new(
+                            ::leptos_fluent::__reexports::fluent_templates::fs::resource_from_str(
+                                include_str!(#path),
+                            ).expect(#message),
+                        )
+                    }
+                });
+
+            // the resources are parsed once and shared by all the contexts
+            quote! {
+                static PSEUDOLOCALE_RESOURCES: ::std::sync::LazyLock<
+                    Vec<::std::sync::Arc<
+                        ::leptos_fluent::__reexports::fluent_templates::fluent_bundle::FluentResource,
+                    >>,
+                > = ::std::sync::LazyLock::new(|| vec![#(#resources_quote),*]);
+                for pseudolocale in [#(#pseudolocales),*] {
+                    for resource in PSEUDOLOCALE_RESOURCES.iter() {
+                        i18n.add_parsed_resource(
+                            pseudolocale,
+                            ::std::sync::Arc::clone(resource),
+                        ).unwrap();
+                    }
+                }
+            }
+        }
+    };
+
+    let on_error_quote: proc_macro2::TokenStream = on_error
+        .iter()
+        .map(|param| {
+            let expr = param.expr.as_ref().unwrap();
+            let quote = quote!(i18n.set_on_error(#expr););
+            match param.exprpath {
+                Some(ref path) => quote!(#path{#quote}),
+                None => quote,
+            }
+        })
+        .collect();
+
+    let use_isolating_quote: proc_macro2::TokenStream = use_isolating
+        .iter()
+        .map(|param| {
+            let expr = param.expr.as_ref().unwrap();
+            let quote = quote!(i18n.set_use_isolating(#expr););
+            match param.exprpath {
+                Some(ref path) => quote!(#path{#quote}),
+                None => quote,
+            }
+        })
+        .collect();
+
+    let functions_quote = {
+        let functions = functions
+            .iter()
+            .flat_map(|loader::Functions(functions)| functions)
+            .filter_map(|(name, function)| {
+                function.as_ref().map(|function| quote!((#name, #function)))
+            });
+        quote! {
+            static FUNCTIONS: &[(&str, ::leptos_fluent::functions::FluentFunction)] =
+                &[#(#functions),*];
+        }
+    };
+
+    let (provide_context_quote, provider_value_quote) = match namespace {
+        Some(ref namespace) => (
+            quote! {
+                ::leptos::context::provide_context(
+                    ::leptos_fluent::I18nNamespaces::current().with(#namespace, i18n),
+                );
+            },
+            quote! {
+                ::leptos_fluent::I18nNamespaces::current().with(#namespace, i18n)
+            },
+        ),
+        None => (
+            quote! {
+                ::leptos::context::provide_context::<::leptos_fluent::I18n>(i18n);
+            },
+            quote! { i18n },
+        ),
+    };
+
+    // The handle of `server_i18n!` negotiates the language of each request
+    // and is not provided as context
+    let (initial_language_quote, provide_context_quote) = match server_i18n {
+        true => (quote!(), quote!()),
+        false => (initial_language_quote, provide_context_quote),
+    };
+
     let init_quote = quote! {
         {
+            #functions_quote
             let mut lang: Option<&'static ::leptos_fluent::Language> = None;
             #initial_language_quote;
 
@@ -2199,11 +2576,133 @@ pub fn leptos_fluent(
                 &LANGUAGES,                                                      // languages
                 ::leptos::prelude::Signal::derive(move || #translations_quote),  // translations
             );
-            ::leptos::context::provide_context::<::leptos_fluent::I18n>(i18n);
+            i18n.set_functions(FUNCTIONS);
+            i18n.set_negotiation_strategy(NEGOTIATION_STRATEGY);
+            #static_bundles_quote
+            #route_segments_quote
+            #pseudolocales_quote
+            #lazy_translations_quote
+            #on_error_quote
+            #use_isolating_quote
+            #provide_context_quote
             i18n
         }
     };
 
+    // Language of the requests for `leptos_fluent::server`
+    #[cfg(feature = "ssr")]
+    let request_language_quote = {
+        let strategy_quote =
+            |params: &[LitBoolExprOrIdent],
+             effect_quote: proc_macro2::TokenStream| {
+                params
+                    .iter()
+                    .map(|param| match param.expr {
+                        Some(ref expr) => {
+                            let q = quote! {
+                                if #expr && lang.is_none() {
+                                    #effect_quote
+                                }
+                            };
+                            match param.exprpath {
+                                Some(ref path) => quote!(#path{#q}),
+                                None => q,
+                            }
+                        }
+                        None => quote!(),
+                    })
+                    .collect::<proc_macro2::TokenStream>()
+            };
+
+        let from_url_param_quote = strategy_quote(
+            &initial_language_from_url_param,
+            quote! {
+                if let Some(l) = req.url_param(&url_param) {
+                    lang = ::leptos_fluent::l_with_strategy(l, &LANGUAGES, NEGOTIATION_STRATEGY);
+                }
+            },
+        );
+        let from_url_path_quote = match url_path {
+            Some(ref ident) => strategy_quote(
+                &initial_language_from_url_path,
+                quote! {
+                    lang = ::leptos_fluent::l_with_strategy(#ident(req.path()), &LANGUAGES, NEGOTIATION_STRATEGY);
+                },
+            ),
+            None => quote!(),
+        };
+        let from_cookie_quote = strategy_quote(
+            &initial_language_from_cookie,
+            quote! {
+                if let Some(cookie) = req.cookie(&cookie_name) {
+                    lang = ::leptos_fluent::l_with_strategy(&cookie, &LANGUAGES, NEGOTIATION_STRATEGY);
+                }
+            },
+        );
+        let from_accept_language_header_quote = strategy_quote(
+            &initial_language_from_accept_language_header,
+            quote! {
+                lang = req.accept_languages().iter().find_map(|l| {
+                    ::leptos_fluent::l_with_strategy(l, &LANGUAGES, NEGOTIATION_STRATEGY)
+                });
+            },
+        );
+        let negotiation_quote = request::with_request_quote(&quote! {
+            #from_url_param_quote
+            #from_url_path_quote
+            #from_cookie_quote
+            #from_accept_language_header_quote
+        });
+
+        quote! {
+            {
+                let url_param = (#url_param_quote).to_string();
+                let cookie_name = (#cookie_name_quote).to_string();
+                move || {
+                    let mut lang: Option<&'static ::leptos_fluent::Language> = None;
+                    #negotiation_quote
+                    lang
+                }
+            }
+        }
+    };
+
+    // Client side, without requests
+    #[cfg(not(feature = "ssr"))]
+    let request_language_quote = quote!(|| None);
+
+    // The handle of `server_i18n!` creates a context for each request,
+    // while the one provided to server renders by contexts without
+    // namespace uses the context of the render
+    let server_i18n_quote = match server_i18n {
+        true => quote! {
+            ::leptos_fluent::server::ServerI18n::new(
+                move || #init_quote,
+                LANGUAGES[#initial_language_index],
+                #request_language_quote,
+            )
+        },
+        false if namespace.is_none() && cfg!(feature = "ssr") => quote! {
+            ::leptos::prelude::provide_context(
+                ::leptos_fluent::server::ServerI18n::new(
+                    move || i18n,
+                    LANGUAGES[#initial_language_index],
+                    #request_language_quote,
+                ),
+            );
+        },
+        false => quote!(),
+    };
+
+    let negotiation_strategy_quote = match negotiation_strategy {
+        loader::NegotiationStrategy::Filtering => quote! {
+            ::leptos_fluent::negotiation::NegotiationStrategy::Filtering
+        },
+        loader::NegotiationStrategy::Lookup => quote! {
+            ::leptos_fluent::negotiation::NegotiationStrategy::Lookup
+        },
+    };
+
     let children_quote: proc_macro2::TokenStream = children
         .iter()
         .map(|param| {
@@ -2215,23 +2714,39 @@ pub fn leptos_fluent(
         })
         .collect();
 
-    let quote = quote! {
-        let i18n = {
-            const LANGUAGES: [&::leptos_fluent::Language; #n_languages] =
-                #languages_quote;
-            let i18n = #init_quote;
-            #other_quotes
-            i18n
-        };
-        {
-            use ::leptos::context::Provider;
-            ::leptos::prelude::view! {
-                <Provider value={i18n}>
-                    #sync_html_tag_quote
-                    {#children_quote}
-                </Provider>
+    let consts_quote = quote! {
+        const LANGUAGES: [&::leptos_fluent::Language; #n_languages] =
+            #languages_quote;
+        const NEGOTIATION_STRATEGY: ::leptos_fluent::negotiation::NegotiationStrategy =
+            #negotiation_strategy_quote;
+    };
+
+    let quote = match server_i18n {
+        true => quote! {
+            {
+                #consts_quote
+                #server_i18n_quote
             }
-        }
+        },
+        false => quote! {
+            let i18n = {
+                #consts_quote
+                let i18n = #init_quote;
+                #server_i18n_quote
+                #other_quotes
+                i18n
+            };
+            {
+                use ::leptos::context::Provider;
+                ::leptos::prelude::view! {
+                    <Provider value={#provider_value_quote}>
+                        #sync_html_tag_quote
+                        #sync_hreflang_links_quote
+                        {#children_quote}
+                    </Provider>
+                }
+            }
+        },
     };
 
     #[cfg(feature = "debug")]
@@ -2240,7 +2755,98 @@ pub fn leptos_fluent(
     #[cfg(feature = "tracing")]
     tracing::trace!("{}", &quote.to_string());
 
-    proc_macro::TokenStream::from(quote)
+    quote
+}
+
+/// Generate typed functions to translate the messages of the FTL files.
+///
+/// For each message, a function that returns the translation and another
+/// one suffixed with `_signal` that returns a [`Signal`] are generated. The
+/// variables of the messages are taken as arguments sorted alphabetically,
+/// and the comments of the messages are used as their documentation.
+///
+/// # Example
+///
+/// ```ftl
+/// # locales/en/main.ftl
+/// # Greeting shown in the header.
+/// hello-args = Hello, { $arg1 } and { $arg2 }!
+/// ```
+///
+/// ```rust,ignore
+/// use leptos::prelude::*;
+/// use leptos_fluent::I18n;
+///
+/// pub mod msgs {
+///     leptos_fluent::messages! {
+///         locales: "./locales",
+///     }
+/// }
+///
+/// #[component]
+/// fn Greeting() -> impl IntoView {
+///     let i18n = expect_context::<I18n>();
+///     let name = RwSignal::new("Jane".to_string());
+///
+///     view! {
+///         <p>{msgs::hello_args_signal(i18n, move || name.get(), || "John")}</p>
+///     }
+/// }
+/// ```
+///
+/// [`Signal`]: https://docs.rs/leptos/latest/leptos/prelude/struct.Signal.html
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+#[proc_macro]
+pub fn messages(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let messages = syn::parse_macro_input!(input as messages::Messages);
+    match messages::build_messages_quote(messages) {
+        Ok(quote) => {
+            #[cfg(feature = "debug")]
+            debug(&format!("\n{}", quote));
+
+            proc_macro::TokenStream::from(quote)
+        }
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Create a static loader of Fluent translations whose messages are
+/// formatted with the functions and the isolation of the contexts of
+/// [`leptos_fluent!`] that use it.
+///
+/// Accepts the parameters of `fluent_templates::static_loader!`, and the
+/// functions of the enabled features, like `NUMBER()`, are installed in its
+/// bundles. When passed with the `translations` parameter of
+/// [`leptos_fluent!`], its messages are formatted with bundles built from
+/// its own resources and `customise` closure, where the `functions` and
+/// `use_isolating` parameters of the context are applied.
+///
+/// ```rust,ignore
+/// leptos_fluent::static_loader! {
+///     static TRANSLATIONS = {
+///         locales: "./locales",
+///         fallback_language: "en",
+///     };
+/// }
+/// ```
+///
+/// [`leptos_fluent!`]: macro.leptos_fluent.html
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+#[proc_macro]
+pub fn static_loader(
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let static_loader =
+        syn::parse_macro_input!(input as static_loader::StaticLoader);
+    match static_loader::build_static_loader_quote(static_loader) {
+        Ok(quote) => {
+            #[cfg(feature = "debug")]
+            debug(&format!("\n{}", quote));
+
+            proc_macro::TokenStream::from(quote)
+        }
+        Err(err) => err.to_compile_error().into(),
+    }
 }
 
 #[cfg(test)]
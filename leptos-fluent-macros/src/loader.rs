@@ -5,7 +5,9 @@ use crate::{
     FluentFilePaths, ParsedLanguage,
 };
 use crate::{
-    fluent_entries::build_fluent_entries,
+    fluent_entries::{
+        build_fluent_entries, build_fluent_function_calls, build_route_segments,
+    },
     tr_macros::{
         gather_tr_macro_defs_from_globstr, gather_tr_macro_defs_from_workspace,
     },
@@ -108,6 +110,17 @@ macro_rules! parse_litstr_or_expr_param_with_maybe_comptime_exprpath {
     };
 }
 
+/// Parse the language extractor of `url_path`, which is the path of a
+/// function or `true` to use the default one.
+fn parse_url_path(input: ParseStream) -> Result<Option<syn::Path>> {
+    match input.parse::<syn::LitBool>() {
+        Ok(lit) => Ok(lit
+            .value
+            .then(|| syn::parse_quote!(::leptos_fluent::url::path::language))),
+        Err(_) => Ok(Some(input.parse()?)),
+    }
+}
+
 fn parse_litbool_or_expr_param(
     input: ParseStream,
     expr: &mut Option<TokenStreamStr>,
@@ -211,7 +224,17 @@ impl Parse for SimpleOrCompound {
 
 /// A collection of loaders (both simple and compound ones) to use
 /// for translating.
-pub(crate) struct Translations(Rc<str>);
+pub(crate) struct Translations {
+    quote: Rc<str>,
+    loaders: Vec<syn::Path>,
+}
+
+impl Translations {
+    /// Loaders passed in the lists of simple loaders.
+    pub(crate) fn loaders(&self) -> &[syn::Path] {
+        &self.loaders
+    }
+}
 
 impl Parse for Translations {
     fn parse(input: ParseStream) -> Result<Self> {
@@ -232,6 +255,7 @@ impl Parse for Translations {
 
         let mut translations_quote =
             "{let mut loaders = Vec::new();".to_string();
+        let mut simple_loaders = Vec::new();
         for loader in loaders.into_iter() {
             match loader {
                 SimpleOrCompound::Simple(x) => {
@@ -240,6 +264,7 @@ impl Parse for Translations {
                             "loaders.push(&{});",
                             loader.to_token_stream()
                         ));
+                        simple_loaders.push(loader);
                     }
                 }
                 SimpleOrCompound::Compound(compound_loader) => {
@@ -253,14 +278,17 @@ impl Parse for Translations {
         }
 
         translations_quote.push_str("loaders}");
-        Ok(Self(Rc::from(translations_quote)))
+        Ok(Self {
+            quote: Rc::from(translations_quote),
+            loaders: simple_loaders,
+        })
     }
 }
 
 impl ToTokens for Translations {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         tokens.extend(proc_macro2::TokenStream::from_iter(
-            self.0
+            self.quote
                 .to_string()
                 .parse::<proc_macro2::TokenStream>()
                 .unwrap(),
@@ -268,6 +296,230 @@ impl ToTokens for Translations {
     }
 }
 
+/// Fallback chains for languages.
+///
+/// ```rust,ignore
+/// fallback_languages: {
+///     "es-MX" => ["es", "en"],
+///     "es" => ["en"],
+/// },
+/// ```
+pub(crate) struct FallbackLanguages(
+    pub(crate) Vec<(syn::LitStr, Vec<syn::LitStr>)>,
+);
+
+impl Parse for FallbackLanguages {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let braced;
+        syn::braced!(braced in input);
+
+        let mut chains = Vec::new();
+        while !braced.is_empty() {
+            let code = braced.parse::<syn::LitStr>()?;
+            braced.parse::<syn::Token![=>]>()?;
+            let bracketed;
+            syn::bracketed!(bracketed in braced);
+            let fallbacks = bracketed.parse_terminated(
+                |i| i.parse::<syn::LitStr>(),
+                syn::Token![,],
+            )?;
+            chains.push((code, fallbacks.into_iter().collect()));
+
+            if braced.is_empty() {
+                break;
+            }
+            braced.parse::<syn::Token![,]>()?;
+        }
+
+        Ok(Self(chains))
+    }
+}
+
+/// Pseudolocales generated from the translations of the default language.
+///
+/// ```rust,ignore
+/// pseudolocales: ["en-XA", "ar-XB"],
+/// ```
+pub(crate) struct Pseudolocales(pub(crate) Vec<syn::LitStr>);
+
+impl Parse for Pseudolocales {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let bracketed;
+        syn::bracketed!(bracketed in input);
+        let pseudolocales = bracketed
+            .parse_terminated(|i| i.parse::<syn::LitStr>(), syn::Token![,])?;
+
+        let mut codes: Vec<syn::LitStr> = Vec::new();
+        for pseudolocale in pseudolocales {
+            if pseudolocale_language(&pseudolocale.value()).is_none() {
+                return Err(syn::Error::new(
+                    pseudolocale.span(),
+                    format!(
+                        concat!(
+                            "Invalid pseudolocale '{}'. Supported pseudolocales",
+                            " are 'en-XA' and 'ar-XB'.",
+                        ),
+                        pseudolocale.value(),
+                    ),
+                ));
+            }
+            if codes.iter().any(|c| c.value() == pseudolocale.value()) {
+                return Err(syn::Error::new(
+                    pseudolocale.span(),
+                    format!(
+                        "Pseudolocale '{}' defined twice.",
+                        pseudolocale.value()
+                    ),
+                ));
+            }
+            codes.push(pseudolocale);
+        }
+
+        Ok(Self(codes))
+    }
+}
+
+/// Strategy to negotiate the languages requested by the initial language
+/// sources.
+///
+/// ```rust,ignore
+/// negotiation_strategy: "lookup",
+/// ```
+#[derive(Clone, Copy, Default)]
+pub(crate) enum NegotiationStrategy {
+    #[default]
+    Filtering,
+    Lookup,
+}
+
+impl Parse for NegotiationStrategy {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let strategy = input.parse::<syn::LitStr>()?;
+        match strategy.value().as_str() {
+            "filtering" => Ok(Self::Filtering),
+            "lookup" => Ok(Self::Lookup),
+            value => Err(syn::Error::new(
+                strategy.span(),
+                format!(
+                    concat!(
+                        "Invalid negotiation strategy '{}'. Supported",
+                        " strategies are 'filtering' and 'lookup'.",
+                    ),
+                    value,
+                ),
+            )),
+        }
+    }
+}
+
+/// Language of a supported pseudolocale.
+fn pseudolocale_language(code: &str) -> Option<ParsedLanguage> {
+    let (name, dir) = match code {
+        "en-XA" => ("English (Pseudo-Accents)", "ltr"),
+        "ar-XB" => ("Arabic (Pseudo-Bidi)", "rtl"),
+        _ => return None,
+    };
+    Some((
+        code.to_string(),
+        name.to_string(),
+        dir.to_string(),
+        None,
+        None,
+        Vec::new(),
+    ))
+}
+
+/// Custom Fluent functions by name.
+///
+/// Names without function are installed by other means, like the
+/// `customise` parameter, and only declared for the translations checker.
+///
+/// ```rust,ignore
+/// functions: {
+///     "SHOUT" => shout,
+///     "REVERSE" => my_crate::reverse,
+///     "UPPER",
+/// },
+/// ```
+pub(crate) struct Functions(pub(crate) Vec<(syn::LitStr, Option<syn::Expr>)>);
+
+impl Parse for Functions {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let braced;
+        syn::braced!(braced in input);
+
+        let mut functions: Vec<(syn::LitStr, Option<syn::Expr>)> = Vec::new();
+        while !braced.is_empty() {
+            let name = braced.parse::<syn::LitStr>()?;
+            if !check_function_name_is_valid(&name.value()) {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!(
+                        concat!(
+                            "Invalid Fluent function name '{}'. Fluent",
+                            " function names must match the regular",
+                            " expression '[A-Z][A-Z0-9_-]*'.",
+                        ),
+                        name.value(),
+                    ),
+                ));
+            }
+            if functions.iter().any(|(n, _)| n.value() == name.value()) {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!(
+                        "Fluent function '{}' defined twice.",
+                        name.value()
+                    ),
+                ));
+            }
+            let function = match braced.peek(syn::Token![=>]) {
+                true => {
+                    braced.parse::<syn::Token![=>]>()?;
+                    Some(braced.parse::<syn::Expr>()?)
+                }
+                false => None,
+            };
+            functions.push((name, function));
+
+            if braced.is_empty() {
+                break;
+            }
+            braced.parse::<syn::Token![,]>()?;
+        }
+
+        Ok(Self(functions))
+    }
+}
+
+fn check_function_name_is_valid(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_uppercase())
+        && chars.all(|c| {
+            c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_' || c == '-'
+        })
+}
+
+/// Translations fetched on demand from a URL prefix.
+///
+/// Contains the paths of the FTL files of each language, relative to the
+/// directory of the language.
+pub(crate) struct LazyTranslations {
+    pub(crate) url: syn::LitStr,
+    pub(crate) files: Vec<(String, Vec<String>)>,
+}
+
+/// Names of the Fluent functions installed by the enabled features.
+fn builtin_function_names() -> Vec<String> {
+    #[allow(unused_mut)]
+    let mut names = Vec::new();
+    #[cfg(feature = "number")]
+    names.push("NUMBER".to_string());
+    #[cfg(feature = "datetime")]
+    names.extend(["DATETIME".to_string(), "RELATIVETIME".to_string()]);
+    names
+}
+
 fn exprpath_not_supported_error_message(expr: &str, k: &syn::Ident) -> String {
     format!(
         concat!(
@@ -446,8 +698,10 @@ impl LitBoolOrStr {
 
 pub(crate) struct I18nLoader {
     pub fluent_file_paths: FluentFilePaths,
+    pub route_segments: Vec<String>,
     pub children: Vec<LitBoolExprOrIdent>,
     pub translations: Option<Translations>,
+    pub lazy_translations: Option<LazyTranslations>,
     pub languages: Vec<ParsedLanguage>,
     pub languages_path: Option<String>,
     pub default_language: Option<(String, usize)>,
@@ -457,9 +711,17 @@ pub(crate) struct I18nLoader {
     pub check_translations: Option<LitBoolOrStr>,
     pub fill_translations: Option<String>,
     pub customise: Option<syn::ExprClosure>,
+    pub functions: Option<Functions>,
+    pub pseudolocales: Vec<String>,
+    pub negotiation_strategy: NegotiationStrategy,
+    pub namespace: Option<String>,
+    pub on_error: Vec<LitBoolExprOrIdent>,
+    pub use_isolating: Vec<LitBoolExprOrIdent>,
     pub provide_meta_context: Vec<LitBool>,
     pub sync_html_tag_lang: Vec<LitBoolExprOrIdent>,
     pub sync_html_tag_dir: Vec<LitBoolExprOrIdent>,
+    pub sync_hreflang_links: Vec<LitBoolExprOrIdent>,
+    pub hreflang_origin: LitStrExpr,
     pub url_param: LitStrExpr,
     pub initial_language_from_url_param: Vec<LitBoolExprOrIdent>,
     pub initial_language_from_url_param_to_local_storage:
@@ -512,7 +774,7 @@ pub(crate) struct I18nLoader {
     pub initial_language_from_server_function_to_local_storage:
         Vec<LitBoolExprOrIdent>,
     pub set_language_to_server_function: Vec<LitBoolExprOrIdent>,
-    pub url_path: Option<syn::Ident>,
+    pub url_path: Option<syn::Path>,
     pub initial_language_from_url_path: Vec<LitBoolExprOrIdent>,
     pub initial_language_from_url_path_to_cookie: Vec<LitBoolExprOrIdent>,
     pub initial_language_from_url_path_to_local_storage:
@@ -521,6 +783,7 @@ pub(crate) struct I18nLoader {
         Vec<LitBoolExprOrIdent>,
     pub initial_language_from_url_path_to_server_function:
         Vec<LitBoolExprOrIdent>,
+    pub set_language_to_url_path: Vec<LitBoolExprOrIdent>,
     #[cfg(feature = "system")]
     pub initial_language_from_system: Vec<LitBoolExprOrIdent>,
     #[cfg(feature = "system")]
@@ -545,12 +808,23 @@ impl Parse for I18nLoader {
         let mut core_locales_path: Option<syn::LitStr> = None;
         let mut default_language: Option<syn::LitStr> = None;
         let mut translations: Option<Translations> = None;
+        let mut lazy_translations_url: Option<syn::LitStr> = None;
+        let mut fallback_languages: Option<FallbackLanguages> = None;
         let mut check_translations: Option<LitBoolOrStr> = None;
         let mut fill_translations: Option<syn::LitStr> = None;
         let mut customise: Option<syn::ExprClosure> = None;
+        let mut functions: Option<Functions> = None;
+        let mut pseudolocales: Option<Pseudolocales> = None;
+        let mut negotiation_strategy: Option<NegotiationStrategy> = None;
+        let mut namespace: Option<syn::LitStr> = None;
+        let mut on_error: Vec<LitBoolExprOrIdent> = Vec::new();
+        let mut use_isolating: Vec<LitBoolExprOrIdent> = Vec::new();
         let mut provide_meta_context: Vec<LitBool> = Vec::new();
         let mut sync_html_tag_lang: Vec<LitBoolExprOrIdent> = Vec::new();
         let mut sync_html_tag_dir: Vec<LitBoolExprOrIdent> = Vec::new();
+        let mut sync_hreflang_links: Vec<LitBoolExprOrIdent> = Vec::new();
+        let mut sync_hreflang_links_span: Option<proc_macro2::Span> = None;
+        let mut hreflang_origin = LitStrExpr::new();
         let mut url_param = LitStrExpr::new();
         let mut initial_language_from_url_param: Vec<LitBoolExprOrIdent> =
             Vec::new();
@@ -638,7 +912,7 @@ impl Parse for I18nLoader {
         > = Vec::new();
         let mut set_language_to_server_function: Vec<LitBoolExprOrIdent> =
             Vec::new();
-        let mut url_path: Option<syn::Ident> = None;
+        let mut url_path: Option<syn::Path> = None;
         let mut initial_language_from_url_path: Vec<LitBoolExprOrIdent> =
             Vec::new();
         let mut initial_language_from_url_path_to_cookie: Vec<
@@ -653,6 +927,7 @@ impl Parse for I18nLoader {
         let mut initial_language_from_url_path_to_server_function: Vec<
             LitBoolExprOrIdent,
         > = Vec::new();
+        let mut set_language_to_url_path: Vec<LitBoolExprOrIdent> = Vec::new();
 
         #[cfg(feature = "system")]
         let mut initial_language_from_system: Vec<LitBoolExprOrIdent> =
@@ -778,6 +1053,17 @@ impl Parse for I18nLoader {
                     k,
                     translations
                 );
+            } else if k == "lazy_translations_url" {
+                check_struct_field_init_shorthand(
+                    struct_field_init_shorthand,
+                    &k,
+                )?;
+                lazy_translations_url = Some(input.parse()?);
+                evaluate_compile_time_exprpath_set_none!(
+                    exprpath_token_stream,
+                    k,
+                    lazy_translations_url
+                );
             } else if k == "locales" {
                 check_struct_field_init_shorthand(
                     struct_field_init_shorthand,
@@ -822,6 +1108,17 @@ impl Parse for I18nLoader {
                     k,
                     languages_path
                 );
+            } else if k == "fallback_languages" {
+                check_struct_field_init_shorthand(
+                    struct_field_init_shorthand,
+                    &k,
+                )?;
+                fallback_languages = Some(input.parse()?);
+                evaluate_compile_time_exprpath_set_none!(
+                    exprpath_token_stream,
+                    k,
+                    fallback_languages
+                );
             } else if k == "check_translations" {
                 check_struct_field_init_shorthand(
                     struct_field_init_shorthand,
@@ -855,6 +1152,85 @@ impl Parse for I18nLoader {
                     k,
                     fill_translations
                 );
+            } else if k == "functions" {
+                check_struct_field_init_shorthand(
+                    struct_field_init_shorthand,
+                    &k,
+                )?;
+                functions = Some(input.parse()?);
+                evaluate_compile_time_exprpath_set_none!(
+                    exprpath_token_stream,
+                    k,
+                    functions
+                );
+            } else if k == "pseudolocales" {
+                check_struct_field_init_shorthand(
+                    struct_field_init_shorthand,
+                    &k,
+                )?;
+                pseudolocales = Some(input.parse()?);
+                evaluate_compile_time_exprpath_set_none!(
+                    exprpath_token_stream,
+                    k,
+                    pseudolocales
+                );
+            } else if k == "negotiation_strategy" {
+                check_struct_field_init_shorthand(
+                    struct_field_init_shorthand,
+                    &k,
+                )?;
+                negotiation_strategy = Some(input.parse()?);
+                evaluate_compile_time_exprpath_set_none!(
+                    exprpath_token_stream,
+                    k,
+                    negotiation_strategy
+                );
+            } else if k == "namespace" {
+                check_struct_field_init_shorthand(
+                    struct_field_init_shorthand,
+                    &k,
+                )?;
+                let lit = input.parse::<syn::LitStr>()?;
+                if lit.value().is_empty() {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "Namespace can't be empty.",
+                    ));
+                }
+                namespace = Some(lit);
+                evaluate_compile_time_exprpath_set_none!(
+                    exprpath_token_stream,
+                    k,
+                    namespace
+                );
+            } else if k == "on_error" {
+                let mut param = LitBoolExprOrIdent::new();
+                parse_runtime_exprpath!(exprpath, param);
+                parse_struct_field_init_shorthand!(
+                    struct_field_init_shorthand,
+                    param,
+                    k_token_stream_str,
+                    on_error
+                );
+                let expr = input.parse::<syn::Expr>()?;
+                param.expr =
+                    Some(expr.to_token_stream().to_string().as_str().into());
+                on_error.push(param);
+            } else if k == "use_isolating" {
+                let mut param = LitBoolExprOrIdent::new();
+                parse_runtime_exprpath!(exprpath, param);
+                parse_struct_field_init_shorthand!(
+                    struct_field_init_shorthand,
+                    param,
+                    k_token_stream_str,
+                    use_isolating
+                );
+                parse_litbool_or_expr_param(
+                    input,
+                    &mut param.expr,
+                    "use_isolating",
+                )?;
+                use_isolating.push(param);
             } else if k == "sync_html_tag_lang" {
                 let mut param = LitBoolExprOrIdent::new();
                 parse_runtime_exprpath!(exprpath, param);
@@ -885,6 +1261,35 @@ impl Parse for I18nLoader {
                     "sync_html_tag_dir",
                 )?;
                 sync_html_tag_dir.push(param);
+            } else if k == "sync_hreflang_links" {
+                sync_hreflang_links_span = Some(k.span());
+                let mut param = LitBoolExprOrIdent::new();
+                parse_runtime_exprpath!(exprpath, param);
+                parse_struct_field_init_shorthand!(
+                    struct_field_init_shorthand,
+                    param,
+                    k_token_stream_str,
+                    sync_hreflang_links
+                );
+                parse_litbool_or_expr_param(
+                    input,
+                    &mut param.expr,
+                    "sync_hreflang_links",
+                )?;
+                sync_hreflang_links.push(param);
+            } else if k == "hreflang_origin" {
+                parse_struct_field_init_shorthand!(
+                    struct_field_init_shorthand,
+                    hreflang_origin,
+                    k_token_stream_str
+                );
+                parse_litstr_or_expr_param_with_maybe_comptime_exprpath!(
+                    exprpath_token_stream,
+                    k,
+                    input,
+                    hreflang_origin,
+                    "hreflang_origin"
+                );
             } else if k == "url_param" {
                 parse_struct_field_init_shorthand!(
                     struct_field_init_shorthand,
@@ -1441,15 +1846,15 @@ impl Parse for I18nLoader {
                         ));
                     } else if evaluated_exprpath.result {
                         if struct_field_init_shorthand {
-                            url_path = Some(k.clone());
+                            url_path = Some(k.clone().into());
                         } else {
-                            url_path = Some(input.parse()?);
+                            url_path = parse_url_path(input)?;
                         }
                     }
                 } else if struct_field_init_shorthand {
-                    url_path = Some(k.clone());
+                    url_path = Some(k.clone().into());
                 } else {
-                    url_path = Some(input.parse()?);
+                    url_path = parse_url_path(input)?;
                 }
             } else if k == "initial_language_from_url_path" {
                 let mut param = LitBoolExprOrIdent::new();
@@ -1523,6 +1928,21 @@ impl Parse for I18nLoader {
                     );
                 }
                 initial_language_from_url_path_to_server_function.push(param);
+            } else if k == "set_language_to_url_path" {
+                let mut param = LitBoolExprOrIdent::new();
+                parse_runtime_exprpath!(exprpath, param);
+                parse_struct_field_init_shorthand!(
+                    struct_field_init_shorthand,
+                    param,
+                    k_token_stream_str,
+                    set_language_to_url_path
+                );
+                parse_litbool_or_expr_param(
+                    input,
+                    &mut param.expr,
+                    "set_language_to_url_path",
+                )?;
+                set_language_to_url_path.push(param);
             } else if k == "initial_language_from_system" {
                 #[cfg(feature = "system")]
                 {
@@ -1688,7 +2108,7 @@ impl Parse for I18nLoader {
             ));
         }
 
-        let languages;
+        let mut languages: Vec<ParsedLanguage>;
         let mut languages_file_path = None;
 
         let languages_file = languages_path
@@ -1760,6 +2180,67 @@ impl Parse for I18nLoader {
             languages = langs;
         }
 
+        // fallback_languages
+        if let Some(FallbackLanguages(ref chains)) = fallback_languages {
+            for (code, fallbacks) in chains {
+                for lit in std::iter::once(code).chain(fallbacks.iter()) {
+                    if !languages.iter().any(|(c, ..)| *c == lit.value()) {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!(
+                                concat!(
+                                    "Language '{}' of 'fallback_languages'",
+                                    " not found in defined languages: {:?}",
+                                ),
+                                lit.value(),
+                                languages
+                                    .iter()
+                                    .map(|(c, ..)| c)
+                                    .collect::<Vec<_>>(),
+                            ),
+                        ));
+                    }
+                }
+                if fallbacks.iter().any(|f| f.value() == code.value()) {
+                    return Err(syn::Error::new(
+                        code.span(),
+                        format!(
+                            concat!(
+                                "Language '{}' of 'fallback_languages'",
+                                " can't fallback to itself.",
+                            ),
+                            code.value(),
+                        ),
+                    ));
+                }
+                let language = languages
+                    .iter_mut()
+                    .find(|(c, ..)| *c == code.value())
+                    .unwrap();
+                language.5 = fallbacks.iter().map(|f| f.value()).collect();
+            }
+        }
+        if let Some(ref langs_path) = languages_path {
+            for (code, .., fallbacks) in &languages {
+                for fallback in fallbacks {
+                    if fallback == code
+                        || !languages.iter().any(|(c, ..)| c == fallback)
+                    {
+                        return Err(syn::Error::new(
+                            langs_path.span(),
+                            format!(
+                                concat!(
+                                    "Invalid fallback language '{}' for",
+                                    " language '{}' in languages file.",
+                                ),
+                                fallback, code,
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
         let locales_path_str =
             locales_folder_path.as_path().to_str().unwrap().to_string();
 
@@ -1807,6 +2288,89 @@ impl Parse for I18nLoader {
             ));
         }
 
+        let route_segments = match cfg!(feature = "router") {
+            true => build_route_segments(
+                &fluent_resources_and_file_paths.0,
+                &core_locales_content,
+            ),
+            false => Vec::new(),
+        };
+
+        // search engines ignore alternate links with relative URLs
+        if let Some(span) = sync_hreflang_links_span {
+            if hreflang_origin.lit.is_none() && hreflang_origin.expr.is_none() {
+                return Err(syn::Error::new(
+                    span,
+                    concat!(
+                        "The parameter 'sync_hreflang_links' requires the",
+                        " 'hreflang_origin' parameter to render absolute URLs.",
+                    ),
+                ));
+            }
+        }
+
+        // lazy_translations_url
+        let lazy_translations = match lazy_translations_url {
+            Some(url) => {
+                if translations.is_some() || customise.is_some() {
+                    return Err(syn::Error::new(
+                        url.span(),
+                        concat!(
+                            "The parameter 'lazy_translations_url' can't be",
+                            " used with 'translations' or 'customise'.",
+                        ),
+                    ));
+                }
+
+                // pages rendered by the server would be hydrated before the
+                // client fetches the translations of their language
+                if cfg!(feature = "ssr") {
+                    return Err(syn::Error::new(
+                        url.span(),
+                        concat!(
+                            "The parameter 'lazy_translations_url' can't be",
+                            " used with server side rendering.",
+                        ),
+                    ));
+                }
+
+                let locales_folder = std::path::absolute(&locales_path_str)
+                    .unwrap_or_else(|_| PathBuf::from(&locales_path_str));
+                let mut files = fluent_resources_and_file_paths
+                    .1
+                    .iter()
+                    .map(|(lang, paths)| {
+                        let language_folder =
+                            locales_folder.join(lang.as_str());
+                        let mut relative_paths = paths
+                            .iter()
+                            .filter_map(|path| {
+                                std::path::absolute(path)
+                                    .ok()?
+                                    .strip_prefix(&language_folder)
+                                    .ok()
+                                    .map(|p| {
+                                        p.components()
+                                            .map(|c| {
+                                                c.as_os_str()
+                                                    .to_string_lossy()
+                                                    .to_string()
+                                            })
+                                            .collect::<Vec<_>>()
+                                            .join("/")
+                                    })
+                            })
+                            .collect::<Vec<_>>();
+                        relative_paths.sort();
+                        (lang.to_string(), relative_paths)
+                    })
+                    .collect::<Vec<_>>();
+                files.sort();
+                Some(LazyTranslations { url, files })
+            }
+            None => None,
+        };
+
         if check_translations.is_some() || fill_translations.is_some() {
             let f_resources_and_file_paths =
                 fluent_resources_and_file_paths.clone();
@@ -1841,6 +2405,7 @@ impl Parse for I18nLoader {
             if let Some(ref fill_translations_globstr) = fill_translations {
                 let fill_messages = crate::translations_filler::run(
                     &fill_translations_globstr.value(),
+                    namespace.as_ref().map(|ns| ns.value()).as_deref(),
                     &manifest_path,
                     &fluent_entries,
                     fluent_file_paths,
@@ -1928,10 +2493,30 @@ impl Parse for I18nLoader {
                         }
                     }
                 };
-                if let Ok(tr_macros) = maybe_tr_macros {
+                if let Ok(mut tr_macros) = maybe_tr_macros {
+                    // only the macros of the namespace of this context
+                    tr_macros.retain(|tr_macro| {
+                        tr_macro.namespace.as_deref()
+                            == namespace
+                                .as_ref()
+                                .map(|ns| ns.value())
+                                .as_deref()
+                    });
+                    let mut known_functions = builtin_function_names();
+                    if let Some(Functions(ref functions)) = functions {
+                        known_functions.extend(
+                            functions.iter().map(|(name, _)| name.value()),
+                        );
+                    }
+                    let fluent_function_calls = build_fluent_function_calls(
+                        fluent_resources,
+                        &core_locales_content,
+                    );
                     let check_messages = crate::translations_checker::run(
                         &fluent_entries,
                         &tr_macros,
+                        &fluent_function_calls,
+                        &known_functions,
                     );
 
                     let mut report = String::new();
@@ -2002,10 +2587,32 @@ impl Parse for I18nLoader {
             None
         };
 
+        // pseudolocales
+        let mut pseudolocale_codes = Vec::new();
+        for lit in pseudolocales.map(|p| p.0).unwrap_or_default() {
+            if languages.iter().any(|(c, ..)| *c == lit.value()) {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!(
+                        concat!(
+                            "Pseudolocale '{}' can't be defined as a language",
+                            " because it's generated from the translations",
+                            " of the default language.",
+                        ),
+                        lit.value(),
+                    ),
+                ));
+            }
+            languages.push(pseudolocale_language(&lit.value()).unwrap());
+            pseudolocale_codes.push(lit.value());
+        }
+
         let loader_ = Self {
             fluent_file_paths: fluent_resources_and_file_paths.1,
+            route_segments,
             children,
             translations,
+            lazy_translations,
             languages,
             languages_path: languages_file_path,
             raw_languages_path: languages_path.map(|x| x.value()),
@@ -2015,9 +2622,17 @@ impl Parse for I18nLoader {
             check_translations,
             fill_translations: fill_translations.map(|x| x.value()),
             customise,
+            functions,
+            pseudolocales: pseudolocale_codes,
+            negotiation_strategy: negotiation_strategy.unwrap_or_default(),
+            namespace: namespace.map(|x| x.value()),
+            on_error,
+            use_isolating,
             provide_meta_context,
             sync_html_tag_lang,
             sync_html_tag_dir,
+            sync_hreflang_links,
+            hreflang_origin,
             url_param,
             initial_language_from_url_param,
             initial_language_from_url_param_to_local_storage,
@@ -2061,6 +2676,7 @@ impl Parse for I18nLoader {
             initial_language_from_url_path_to_local_storage,
             initial_language_from_url_path_to_session_storage,
             initial_language_from_url_path_to_server_function,
+            set_language_to_url_path,
             #[cfg(feature = "system")]
             initial_language_from_system,
             #[cfg(feature = "system")]
@@ -0,0 +1,351 @@
+use crate::fluent_entries::build_fluent_entries;
+use crate::{build_fluent_resources_and_file_paths, FluentResources};
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::PathBuf;
+use syn::{
+    parse::{Parse, ParseStream},
+    token, Result,
+};
+
+/// Parameters of the `messages!` macro.
+pub(crate) struct Messages {
+    locales: syn::LitStr,
+    core_locales: Option<syn::LitStr>,
+}
+
+impl Parse for Messages {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut locales: Option<syn::LitStr> = None;
+        let mut core_locales: Option<syn::LitStr> = None;
+
+        while !input.is_empty() {
+            let k = input.parse::<syn::Ident>()?;
+            input.parse::<token::Colon>()?;
+            if k == "locales" {
+                locales = Some(input.parse()?);
+            } else if k == "core_locales" {
+                core_locales = Some(input.parse()?);
+            } else {
+                return Err(syn::Error::new(
+                    k.span(),
+                    "Not a valid parameter.",
+                ));
+            }
+
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<token::Comma>()?;
+        }
+
+        match locales {
+            Some(locales) => Ok(Self {
+                locales,
+                core_locales,
+            }),
+            None => Err(syn::Error::new(
+                input.span(),
+                "Missing `locales` parameter",
+            )),
+        }
+    }
+}
+
+/// Convert a Fluent identifier to a Rust identifier.
+///
+/// Dashes and dots of attributes are replaced by underscores and Rust
+/// keywords are escaped as raw identifiers.
+fn fluent_id_to_rust_ident(id: &str) -> syn::Ident {
+    let name = id.replace(['-', '.'], "_");
+    syn::parse_str::<syn::Ident>(&name).unwrap_or_else(|_| {
+        syn::Ident::new_raw(&name, proc_macro2::Span::call_site())
+    })
+}
+
+/// Get the comments of the messages of the resources.
+///
+/// The comment of a message is the first one found for it in any language.
+fn build_message_comments(
+    fluent_resources: &FluentResources,
+    core_locales_content: &Option<String>,
+) -> HashMap<String, String> {
+    let mut comments = HashMap::new();
+
+    let mut languages = fluent_resources.keys().collect::<Vec<_>>();
+    languages.sort();
+    let resources_strs = languages
+        .into_iter()
+        .flat_map(|lang| fluent_resources[lang].iter())
+        .chain(core_locales_content.iter());
+    for resource_str in resources_strs {
+        // `FluentResource` parses in runtime mode, which skips comments
+        let resource = match fluent_syntax::parser::parse(resource_str.as_str())
+        {
+            Ok(resource) => resource,
+            Err((resource, _)) => resource,
+        };
+        for entry in resource.body {
+            if let fluent_syntax::ast::Entry::Message(msg) = entry {
+                if let Some(comment) = msg.comment {
+                    comments
+                        .entry(msg.id.name.to_string())
+                        .or_insert_with(|| comment.content.join("\n"));
+                }
+            }
+        }
+    }
+
+    comments
+}
+
+pub(crate) fn build_messages_quote(
+    messages: Messages,
+) -> Result<proc_macro2::TokenStream> {
+    let manifest_path = PathBuf::from(
+        std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| "./".into()),
+    );
+
+    let locales_folder_path = manifest_path.join(messages.locales.value());
+    if std::fs::metadata(&locales_folder_path).is_err() {
+        let file_path = std::path::absolute(&locales_folder_path)
+            .unwrap_or(locales_folder_path);
+
+        return Err(syn::Error::new(
+            messages.locales.span(),
+            format!(
+                concat!(
+                    "Couldn't read locales folder. This path should",
+                    " be relative to your crate's `Cargo.toml`.",
+                    " Looking for: {:?}",
+                ),
+                file_path,
+            ),
+        ));
+    }
+
+    let mut core_locales_path_str = None;
+    let mut core_locales_content = None;
+    if let Some(ref core_locales) = messages.core_locales {
+        let core_locales_path = manifest_path.join(core_locales.value());
+        match std::fs::read_to_string(&core_locales_path) {
+            Ok(content) => {
+                core_locales_content = Some(content);
+                core_locales_path_str =
+                    Some(core_locales_path.to_str().unwrap().to_string());
+            }
+            Err(_) => {
+                let file_path = std::path::absolute(&core_locales_path)
+                    .unwrap_or(core_locales_path);
+
+                return Err(syn::Error::new(
+                    core_locales.span(),
+                    format!(
+                        concat!(
+                            "Couldn't read core fluent resource. This path should",
+                            " be relative to your crate's `Cargo.toml`.",
+                            " Looking for: {:?}",
+                        ),
+                        file_path,
+                    ),
+                ));
+            }
+        }
+    }
+
+    let ((fluent_resources, fluent_file_paths), mut errors) =
+        build_fluent_resources_and_file_paths(&locales_folder_path);
+    let fluent_entries = build_fluent_entries(
+        &fluent_resources,
+        &fluent_file_paths,
+        &manifest_path,
+        &core_locales_path_str,
+        &core_locales_content,
+        &mut errors,
+    );
+    if !errors.is_empty() {
+        return Err(syn::Error::new(
+            messages.locales.span(),
+            format!("Unrecoverable errors:\n- {}", errors.join("\n- ")),
+        ));
+    }
+
+    let comments =
+        build_message_comments(&fluent_resources, &core_locales_content);
+
+    // variables of the messages of all languages sorted alphabetically
+    let mut variables_by_message: BTreeMap<String, BTreeSet<String>> =
+        BTreeMap::new();
+    for entries in fluent_entries.values() {
+        for entry in entries {
+            variables_by_message
+                .entry(entry.message_name.clone())
+                .or_default()
+                .extend(entry.placeables.iter().cloned());
+        }
+    }
+
+    let mut function_names: HashMap<String, String> = HashMap::new();
+    let mut functions = Vec::new();
+    for (message_name, variables) in &variables_by_message {
+        let fn_ident = fluent_id_to_rust_ident(message_name);
+        let signal_fn_ident = format_ident!(
+            "{}_signal",
+            fn_ident.to_string().trim_start_matches("r#")
+        );
+        for ident in [&fn_ident, &signal_fn_ident] {
+            if let Some(other) =
+                function_names.insert(ident.to_string(), message_name.clone())
+            {
+                return Err(syn::Error::new(
+                    messages.locales.span(),
+                    format!(
+                        concat!(
+                            "Messages \"{}\" and \"{}\" generate the same",
+                            " function name `{}`.",
+                        ),
+                        other, message_name, ident,
+                    ),
+                ));
+            }
+        }
+
+        let mut doc = comments
+            .get(message_name.split('.').next().unwrap())
+            .map(|comment| format!("{comment}\n\n"))
+            .unwrap_or_default();
+        doc.push_str(&format!("Translate the `{message_name}` message."));
+        let doc_quote = doc.lines().map(|line| {
+            let line = format!(" {line}");
+            quote!(#[doc = #line])
+        });
+        let doc_quote = quote!(#(#doc_quote)*);
+
+        if variables.is_empty() {
+            functions.push(quote! {
+                #doc_quote
+                #[allow(non_snake_case)]
+                pub fn #fn_ident(i18n: ::leptos_fluent::I18n) -> String {
+                    i18n.tr(#message_name)
+                }
+
+                #doc_quote
+                #[allow(non_snake_case)]
+                pub fn #signal_fn_ident(
+                    i18n: ::leptos_fluent::I18n,
+                ) -> ::leptos::prelude::Signal<String> {
+                    ::leptos::prelude::Signal::derive(move || #fn_ident(i18n))
+                }
+            });
+            continue;
+        }
+
+        let mut arg_idents = Vec::with_capacity(variables.len());
+        for variable in variables {
+            let mut arg_ident = fluent_id_to_rust_ident(variable);
+            if arg_ident == "i18n" {
+                arg_ident = format_ident!("i18n_");
+            }
+            if arg_idents.contains(&arg_ident) {
+                return Err(syn::Error::new(
+                    messages.locales.span(),
+                    format!(
+                        concat!(
+                            "Variables of message \"{}\" generate the same",
+                            " argument name `{}`.",
+                        ),
+                        message_name, arg_ident,
+                    ),
+                ));
+            }
+            arg_idents.push(arg_ident);
+        }
+        let generics = (0..variables.len())
+            .map(|i| format_ident!("A{i}"))
+            .collect::<Vec<_>>();
+        let variables = variables.iter();
+
+        functions.push(quote! {
+            #doc_quote
+            #[allow(non_snake_case, clippy::too_many_arguments)]
+            pub fn #fn_ident<'a>(
+                i18n: ::leptos_fluent::I18n,
+                #(#arg_idents: impl Into<::leptos_fluent::__reexports::fluent_bundle::FluentValue<'a>>,)*
+            ) -> String {
+                let mut args = ::std::collections::HashMap::new();
+                #(args.insert(::std::borrow::Cow::Borrowed(#variables), #arg_idents.into());)*
+                i18n.tr_with_args(#message_name, &args)
+            }
+
+            #doc_quote
+            #[allow(non_snake_case, clippy::too_many_arguments)]
+            pub fn #signal_fn_ident<#(#generics),*>(
+                i18n: ::leptos_fluent::I18n,
+                #(#arg_idents: impl Fn() -> #generics + Send + Sync + 'static,)*
+            ) -> ::leptos::prelude::Signal<String>
+            where
+                #(#generics: Into<::leptos_fluent::__reexports::fluent_bundle::FluentValue<'static>>,)*
+            {
+                ::leptos::prelude::Signal::derive(move || #fn_ident(i18n, #(#arg_idents()),*))
+            }
+        });
+    }
+
+    // rebuild when the FTL files change
+    let files_tracker_quote = fluent_file_paths
+        .values()
+        .flatten()
+        .chain(core_locales_path_str.iter())
+        .map(|path| {
+            quote!(
+                const _: &[u8] = include_bytes!(#path);
+            )
+        });
+
+    Ok(quote! {
+        #(#files_tracker_quote)*
+        #(#functions)*
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn rust_idents() {
+        assert_eq!(fluent_id_to_rust_ident("hello-args"), "hello_args");
+        assert_eq!(
+            fluent_id_to_rust_ident("login-input.placeholder"),
+            "login_input_placeholder"
+        );
+        assert_eq!(fluent_id_to_rust_ident("type").to_string(), "r#type");
+    }
+
+    #[test]
+    fn message_comments() {
+        let fluent_resources = HashMap::from([
+            (
+                Rc::new("en".to_string()),
+                vec![
+                    "# Greeting\n# with name.\nhello = Hello { $name }\nbye = Bye\n"
+                        .to_string(),
+                ],
+            ),
+            (
+                Rc::new("es".to_string()),
+                vec!["hello = Hola { $name }\n# Farewell\nbye = Adiós\n"
+                    .to_string()],
+            ),
+        ]);
+        let comments = build_message_comments(&fluent_resources, &None);
+        assert_eq!(
+            comments,
+            HashMap::from([
+                ("hello".to_string(), "Greeting\nwith name.".to_string()),
+                ("bye".to_string(), "Farewell".to_string()),
+            ])
+        );
+    }
+}
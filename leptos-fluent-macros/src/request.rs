@@ -0,0 +1,49 @@
+/// Wraps code that reads the request of the server, available as `req`, a
+/// `&dyn leptos_fluent::server::RequestLanguageSource`.
+///
+/// The request provided by `leptos_actix` or `leptos_axum` is used with the
+/// `actix` or `axum` features, falling back to the `RequestSource` context
+/// provided by other servers. The code is not executed without request.
+pub(crate) fn with_request_quote(
+    body: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    use quote::quote;
+
+    #[cfg(feature = "actix")]
+    let framework_request_quote = quote! {
+        let framework_req = ::leptos::prelude::use_context::<::leptos_actix::Request>();
+        let framework_req = framework_req
+            .as_deref()
+            .map(|req| req as &dyn ::leptos_fluent::server::RequestLanguageSource);
+    };
+
+    #[cfg(feature = "axum")]
+    let framework_request_quote = quote! {
+        let framework_req =
+            ::leptos::prelude::use_context::<::axum::http::request::Parts>();
+        let framework_req = framework_req
+            .as_ref()
+            .map(|req| req as &dyn ::leptos_fluent::server::RequestLanguageSource);
+    };
+
+    // Other SSR framework or the user is not using any
+    #[cfg(all(not(feature = "actix"), not(feature = "axum")))]
+    let framework_request_quote = quote! {
+        let framework_req: Option<&dyn ::leptos_fluent::server::RequestLanguageSource> =
+            None;
+    };
+
+    quote! {
+        {
+            #framework_request_quote
+            let source_req =
+                ::leptos::prelude::use_context::<::leptos_fluent::server::RequestSource>();
+            let source_req = source_req
+                .as_deref()
+                .map(|req| req as &dyn ::leptos_fluent::server::RequestLanguageSource);
+            if let Some(req) = framework_req.or(source_req) {
+                #body
+            }
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use crate::build_fluent_resources_and_file_paths;
+use quote::quote;
+use std::path::PathBuf;
+use syn::{
+    parse::{Parse, ParseStream},
+    token, Result,
+};
+
+/// Parameters of the `static_loader!` macro, like the ones of
+/// `fluent_templates::static_loader!`.
+pub(crate) struct StaticLoader {
+    vis: syn::Visibility,
+    name: syn::Ident,
+    locales: syn::LitStr,
+    fallback_language: syn::LitStr,
+    core_locales: Option<syn::LitStr>,
+    customise: Option<syn::Expr>,
+}
+
+impl Parse for StaticLoader {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let vis = input.parse::<syn::Visibility>()?;
+        input.parse::<token::Static>()?;
+        let name = input.parse::<syn::Ident>()?;
+        input.parse::<token::Eq>()?;
+        let fields;
+        syn::braced!(fields in input);
+
+        let mut locales: Option<syn::LitStr> = None;
+        let mut fallback_language: Option<syn::LitStr> = None;
+        let mut core_locales: Option<syn::LitStr> = None;
+        let mut customise: Option<syn::Expr> = None;
+
+        while !fields.is_empty() {
+            let k = fields.parse::<syn::Ident>()?;
+            fields.parse::<token::Colon>()?;
+            if k == "locales" {
+                locales = Some(fields.parse()?);
+            } else if k == "fallback_language" {
+                fallback_language = Some(fields.parse()?);
+            } else if k == "core_locales" {
+                core_locales = Some(fields.parse()?);
+            } else if k == "customise" {
+                customise = Some(fields.parse()?);
+            } else {
+                return Err(syn::Error::new(
+                    k.span(),
+                    "Not a valid parameter.",
+                ));
+            }
+
+            if fields.is_empty() {
+                break;
+            }
+            fields.parse::<token::Comma>()?;
+        }
+        input.parse::<token::Semi>()?;
+
+        let Some(locales) = locales else {
+            return Err(syn::Error::new(
+                name.span(),
+                "Missing `locales` parameter",
+            ));
+        };
+        let Some(fallback_language) = fallback_language else {
+            return Err(syn::Error::new(
+                name.span(),
+                "Missing `fallback_language` parameter",
+            ));
+        };
+
+        Ok(Self {
+            vis,
+            name,
+            locales,
+            fallback_language,
+            core_locales,
+            customise,
+        })
+    }
+}
+
+pub(crate) fn build_static_loader_quote(
+    static_loader: StaticLoader,
+) -> Result<proc_macro2::TokenStream> {
+    let StaticLoader {
+        vis,
+        name,
+        locales,
+        fallback_language,
+        core_locales,
+        customise,
+    } = static_loader;
+
+    let manifest_path = PathBuf::from(
+        std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| "./".into()),
+    );
+
+    let locales_folder_path = manifest_path.join(locales.value());
+    if std::fs::metadata(&locales_folder_path).is_err() {
+        let file_path = std::path::absolute(&locales_folder_path)
+            .unwrap_or(locales_folder_path);
+
+        return Err(syn::Error::new(
+            locales.span(),
+            format!(
+                concat!(
+                    "Couldn't read locales folder. This path should",
+                    " be relative to your crate's `Cargo.toml`.",
+                    " Looking for: {:?}",
+                ),
+                file_path,
+            ),
+        ));
+    }
+
+    let core_resource_quote = match core_locales {
+        Some(ref core_locales) => {
+            let core_locales_path = manifest_path.join(core_locales.value());
+            if std::fs::metadata(&core_locales_path).is_err() {
+                let file_path = std::path::absolute(&core_locales_path)
+                    .unwrap_or(core_locales_path);
+
+                return Err(syn::Error::new(
+                    core_locales.span(),
+                    format!(
+                        concat!(
+                            "Couldn't read core fluent resource. This path should",
+                            " be relative to your crate's `Cargo.toml`.",
+                            " Looking for: {:?}",
+                        ),
+                        file_path,
+                    ),
+                ));
+            }
+            let path = core_locales_path.to_str().unwrap();
+            quote! {
+                Some(
+                    fluent_templates::fs::resource_from_str(include_str!(#path))
+                        .expect("Couldn't load core resources"),
+                )
+            }
+        }
+        None => quote!(None),
+    };
+
+    let ((_, fluent_file_paths), errors) =
+        build_fluent_resources_and_file_paths(&locales_folder_path);
+    if !errors.is_empty() {
+        return Err(syn::Error::new(
+            locales.span(),
+            format!("Unrecoverable errors:\n- {}", errors.join("\n- ")),
+        ));
+    }
+
+    let fallback_language_value = fallback_language.value();
+    if fallback_language_value
+        .parse::<fluent_templates::LanguageIdentifier>()
+        .is_err()
+    {
+        return Err(syn::Error::new(
+            fallback_language.span(),
+            format!(
+                "Invalid language identifier \"{fallback_language_value}\" for fallback language",
+            ),
+        ));
+    }
+    if !fluent_file_paths
+        .keys()
+        .any(|lang| **lang == fallback_language_value)
+    {
+        return Err(syn::Error::new(
+            fallback_language.span(),
+            "Fallback language not found in locales directory",
+        ));
+    }
+
+    let mut locales = fluent_file_paths.iter().collect::<Vec<_>>();
+    locales.sort();
+    let resources_quote = locales.into_iter().map(|(lang, paths)| {
+        let lang = lang.as_str();
+        let mut paths = paths.clone();
+        paths.sort();
+        quote! {
+            (
+                #lang.parse().unwrap(),
+                vec![#(
+                    fluent_templates::fs::resource_from_str(include_str!(#paths)).unwrap()
+                ),*],
+            )
+        }
+    });
+
+    let customise_quote = match customise {
+        Some(c) => quote! {
+            let customise: &dyn Fn(
+                &mut fluent_templates::FluentBundle<
+                    &'static fluent_templates::fluent_bundle::FluentResource,
+                >,
+            ) = &#c;
+            customise(bundle);
+        },
+        None => quote!(),
+    };
+
+    // the loader is built like `fluent_templates::static_loader!` does, but
+    // registering its sources to build the bundles of the contexts
+    Ok(quote! {
+        #vis static #name: ::std::sync::LazyLock<
+            ::leptos_fluent::__reexports::fluent_templates::StaticLoader,
+        > = ::std::sync::LazyLock::new(|| {
+            use ::leptos_fluent::__reexports::fluent_templates;
+            static RESOURCES: ::std::sync::LazyLock<
+                ::std::collections::HashMap<
+                    fluent_templates::LanguageIdentifier,
+                    Vec<fluent_templates::fluent_bundle::FluentResource>,
+                >,
+            > = ::std::sync::LazyLock::new(|| {
+                ::std::collections::HashMap::from([#(#resources_quote),*])
+            });
+            static CORE_RESOURCE: ::std::sync::LazyLock<
+                Option<fluent_templates::fluent_bundle::FluentResource>,
+            > = ::std::sync::LazyLock::new(|| #core_resource_quote);
+            fn customise(
+                bundle: &mut fluent_templates::FluentBundle<
+                    &'static fluent_templates::fluent_bundle::FluentResource,
+                >,
+            ) {
+                #customise_quote
+            }
+            static BUNDLES: ::std::sync::LazyLock<::leptos_fluent::StaticBundles> =
+                ::std::sync::LazyLock::new(|| {
+                    fluent_templates::loader::build_bundles(
+                        &RESOURCES,
+                        CORE_RESOURCE.as_ref(),
+                        |bundle| {
+                            ::leptos_fluent::functions::add_functions(bundle);
+                            customise(bundle);
+                        },
+                    )
+                });
+            static FALLBACKS: ::std::sync::LazyLock<
+                ::std::collections::HashMap<
+                    fluent_templates::LanguageIdentifier,
+                    Vec<fluent_templates::LanguageIdentifier>,
+                >,
+            > = ::std::sync::LazyLock::new(|| {
+                fluent_templates::loader::build_fallbacks(
+                    &RESOURCES.keys().cloned().collect::<Vec<_>>(),
+                )
+            });
+
+            ::leptos_fluent::LoaderSources {
+                resources: &RESOURCES,
+                core_resource: &CORE_RESOURCE,
+                customise,
+            }
+            .register(&#name);
+            fluent_templates::StaticLoader::new(
+                &BUNDLES,
+                &FALLBACKS,
+                #fallback_language.parse().unwrap(),
+            )
+        });
+    })
+}
@@ -175,11 +175,23 @@ fn tr_macros_from_file_path(
     }
 }
 
+/// Names of the translation macros recognised by the checker.
+const TR_MACROS_NAMES: [&str; 4] = ["tr", "move_tr", "try_tr", "try_move_tr"];
+
+/// Name of the component recognised by the checker.
+pub(crate) const TR_COMPONENT_NAME: &str = "Tr";
+
+fn is_tr_macro_name(name: &str) -> bool {
+    TR_MACROS_NAMES.contains(&name)
+}
+
 #[cfg_attr(test, derive(Clone))]
 pub(crate) struct TranslationMacro {
     pub(crate) name: String,
     pub(crate) message_name: String,
     pub(crate) placeables: Vec<String>,
+    /// Namespace of the `I18n` context used by the macro (`ns = "..."`).
+    pub(crate) namespace: Option<String>,
     #[cfg(feature = "nightly")]
     pub(crate) start: proc_macro2::LineColumn,
 
@@ -188,11 +200,20 @@ pub(crate) struct TranslationMacro {
     pub(crate) file_path: std::rc::Rc<String>,
 }
 
+impl TranslationMacro {
+    /// Whether the macro is fallible (`try_tr!` or `try_move_tr!`), so the
+    /// message is not required to be defined for all languages.
+    pub(crate) fn is_fallible(&self) -> bool {
+        self.name.starts_with("try_")
+    }
+}
+
 impl PartialEq for TranslationMacro {
     fn eq(&self, other: &Self) -> bool {
         let equal = self.name == other.name
             && self.message_name == other.message_name
-            && self.placeables == other.placeables;
+            && self.placeables == other.placeables
+            && self.namespace == other.namespace;
         #[cfg(all(not(test), not(feature = "nightly")))]
         return equal && self.file_path == other.file_path;
         #[cfg(all(not(test), feature = "nightly"))]
@@ -208,10 +229,11 @@ impl Debug for TranslationMacro {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "TranslationMacro {{ name: {name:?}, message_name: {message_name:?}, placeables: {placeables:?} }}",
+            "TranslationMacro {{ name: {name:?}, message_name: {message_name:?}, placeables: {placeables:?}, namespace: {namespace:?} }}",
             name = self.name,
             message_name = self.message_name,
             placeables = self.placeables,
+            namespace = self.namespace,
         )
     }
 }
@@ -247,6 +269,7 @@ pub(crate) struct TranslationsMacrosVisitor<'a> {
     current_tr_macro: Option<String>,
     current_tr_macro_punct: Option<char>,
     current_messages: Option<CurrentMessages>,
+    current_tr_macro_namespace: Option<String>,
     #[cfg(feature = "nightly")]
     current_tr_macro_start: Option<proc_macro2::LineColumn>,
 
@@ -352,6 +375,53 @@ fn parse_if_elseif_else(
     n_parsed_tokens
 }
 
+/// Remove the leading `option = value,` arguments of a translation macro
+/// call, like `use_isolating = false,`.
+///
+/// Returns the value of the `ns` option, if any, along with the stripped
+/// group.
+fn strip_tr_macro_options(
+    group: &proc_macro2::Group,
+) -> (proc_macro2::Group, Option<String>) {
+    let tokens = group.stream().into_iter().collect::<Vec<_>>();
+    let mut start = 0;
+    let mut namespace = None;
+    while let (
+        Some(proc_macro2::TokenTree::Ident(option)),
+        Some(proc_macro2::TokenTree::Punct(punct)),
+    ) = (tokens.get(start), tokens.get(start + 1))
+    {
+        if punct.as_char() != '=' {
+            break;
+        }
+        if option == "ns" {
+            if let Some(proc_macro2::TokenTree::Literal(literal)) =
+                tokens.get(start + 2)
+            {
+                if let syn::Lit::Str(litstr) = syn::Lit::new(literal.clone()) {
+                    namespace = Some(litstr.value());
+                }
+            }
+        }
+        match tokens[start + 2..].iter().position(|token| {
+            matches!(token, proc_macro2::TokenTree::Punct(p) if p.as_char() == ',')
+        }) {
+            Some(comma_index) => start += comma_index + 3,
+            None => break,
+        }
+    }
+
+    if start == 0 {
+        return (group.clone(), namespace);
+    }
+    let mut stripped = proc_macro2::Group::new(
+        group.delimiter(),
+        tokens[start..].iter().cloned().collect(),
+    );
+    stripped.set_span(group.span());
+    (stripped, namespace)
+}
+
 impl<'a> TranslationsMacrosVisitor<'a> {
     fn new(
         tr_macros: &'a mut Vec<TranslationMacro>,
@@ -371,6 +441,7 @@ impl<'a> TranslationsMacrosVisitor<'a> {
             current_tr_macro: None,
             current_tr_macro_punct: None,
             current_messages: None,
+            current_tr_macro_namespace: None,
             current_use_path_is_leptos_fluent: false,
             #[cfg(not(test))]
             file_path: std::rc::Rc::new(rel_path),
@@ -381,17 +452,136 @@ impl<'a> TranslationsMacrosVisitor<'a> {
 }
 
 impl TranslationsMacrosVisitor<'_> {
+    /// Gather the `<Tr/>` components of a `view!` macro.
+    ///
+    /// ```ignore
+    /// <Tr id="text-id" args=vec![("placeable1", value1.into())] />
+    /// ```
+    fn visit_tr_components(&mut self, tokens: &proc_macro2::TokenStream) {
+        let tokens = tokens.clone().into_iter().collect::<Vec<_>>();
+        for (i, token) in tokens.iter().enumerate() {
+            let proc_macro2::TokenTree::Ident(ident) = token else {
+                continue;
+            };
+            if ident != TR_COMPONENT_NAME
+                || !matches!(
+                    i.checked_sub(1).map(|prev| &tokens[prev]),
+                    Some(proc_macro2::TokenTree::Punct(p)) if p.as_char() == '<'
+                )
+            {
+                continue;
+            }
+
+            let mut message_name = None;
+            let mut placeables = Vec::new();
+            let mut namespace = None;
+            let mut j = i + 1;
+            while let Some(token) = tokens.get(j) {
+                match token {
+                    proc_macro2::TokenTree::Punct(p) if p.as_char() == '>' => {
+                        break;
+                    }
+                    proc_macro2::TokenTree::Ident(key)
+                        if matches!(
+                            tokens.get(j + 1),
+                            Some(proc_macro2::TokenTree::Punct(p)) if p.as_char() == '='
+                        ) =>
+                    {
+                        let value = tokens.get(j + 2);
+                        if key == "id" || key == "ns" {
+                            if let Some(proc_macro2::TokenTree::Literal(lit)) =
+                                value
+                            {
+                                match value_from_literal_str(
+                                    &lit.to_string(),
+                                    TR_COMPONENT_NAME,
+                                ) {
+                                    Ok(value) if key == "id" => {
+                                        message_name = Some(value.to_owned())
+                                    }
+                                    Ok(value) => {
+                                        namespace = Some(value.to_owned())
+                                    }
+                                    Err(error) => {
+                                        if !self.errors.contains(&error) {
+                                            self.errors.push(error);
+                                        }
+                                    }
+                                }
+                            }
+                        } else if key == "args" {
+                            // `[...]` or `vec![...]`
+                            let args_group = match (value, tokens.get(j + 4)) {
+                                (
+                                    Some(proc_macro2::TokenTree::Group(group)),
+                                    _,
+                                ) => Some(group),
+                                (
+                                    Some(proc_macro2::TokenTree::Ident(vec)),
+                                    Some(proc_macro2::TokenTree::Group(group)),
+                                ) if vec == "vec" => Some(group),
+                                _ => None,
+                            };
+                            for arg in args_group
+                                .map(|group| group.stream().into_iter())
+                                .into_iter()
+                                .flatten()
+                            {
+                                let proc_macro2::TokenTree::Group(arg) = arg
+                                else {
+                                    continue;
+                                };
+                                if let Some(proc_macro2::TokenTree::Literal(
+                                    lit,
+                                )) = arg.stream().into_iter().next()
+                                {
+                                    if let Ok(value) = value_from_literal_str(
+                                        &lit.to_string(),
+                                        TR_COMPONENT_NAME,
+                                    ) {
+                                        placeables.push(value.to_owned());
+                                    }
+                                }
+                            }
+                        }
+                        j += 2;
+                    }
+                    _ => {}
+                }
+                j += 1;
+            }
+
+            if let Some(message_name) = message_name {
+                let tr_macro = TranslationMacro {
+                    name: TR_COMPONENT_NAME.to_string(),
+                    message_name,
+                    placeables,
+                    namespace,
+                    #[cfg(not(test))]
+                    file_path: std::rc::Rc::clone(&self.file_path),
+                    #[cfg(feature = "nightly")]
+                    start: ident.span().start(),
+                };
+                if !self.tr_macros.contains(&tr_macro) {
+                    self.tr_macros.push(tr_macro);
+                }
+            }
+        }
+    }
+
     fn visit_maybe_macro_tokens_stream(
         &mut self,
         tokens: &proc_macro2::TokenStream,
     ) {
+        self.visit_tr_components(tokens);
+
         // println!("\ntokens: {:#?}\n----------------------", tokens);
 
         // Inside a macro group like `view!`
         for token in tokens.clone() {
             if let proc_macro2::TokenTree::Ident(ref ident) = token {
                 let ident_str = ident.to_string();
-                if ident_str == "move_tr" || ident_str == "tr" {
+                if is_tr_macro_name(&ident_str) {
                     self.current_tr_macro = Some(ident.to_string());
                     #[cfg(feature = "nightly")]
                     {
@@ -431,6 +621,9 @@ impl TranslationsMacrosVisitor<'_> {
                     continue;
                 }
 
+                let (group, namespace) = strip_tr_macro_options(group);
+                let group = &group;
+                self.current_tr_macro_namespace = namespace;
                 let mut n_parsed_tokens = 0;
 
                 let group_first_token =
@@ -668,6 +861,9 @@ impl TranslationsMacrosVisitor<'_> {
                                     .to_owned(),
                                 message_name: message_name.to_owned(),
                                 placeables: placables.to_owned(),
+                                namespace: self
+                                    .current_tr_macro_namespace
+                                    .clone(),
                                 #[cfg(not(test))]
                                 file_path: std::rc::Rc::clone(&self.file_path),
                                 #[cfg(feature = "nightly")]
@@ -685,6 +881,9 @@ impl TranslationsMacrosVisitor<'_> {
                                         .to_owned(),
                                     message_name: message_name.to_owned(),
                                     placeables: placeables.to_owned(),
+                                    namespace: self
+                                        .current_tr_macro_namespace
+                                        .clone(),
                                     #[cfg(not(test))]
                                     file_path: std::rc::Rc::clone(
                                         &self.file_path,
@@ -754,7 +953,7 @@ impl<'ast> Visit<'ast> for TranslationsMacrosVisitor<'_> {
     fn visit_use_rename(&mut self, node: &'ast syn::UseRename) {
         if self.current_use_path_is_leptos_fluent {
             let ident = node.ident.to_string();
-            if ident == "tr" || ident == "move_tr" {
+            if is_tr_macro_name(&ident) {
                 let rename_ident = node.rename.to_string();
                 self.errors.push(format!(
                     "Importing `{ident}` as `{rename_ident}` is not allowed because breaks leptos-fluent's compile-time checking of translations."
@@ -767,7 +966,7 @@ impl<'ast> Visit<'ast> for TranslationsMacrosVisitor<'_> {
                 self.errors.push(format!(
                     "Importing `leptos-fluent` as `{rename_ident}` is not allowed because breaks leptos-fluent's compile-time checking of translations."
                 ));
-            } else if rename_ident == "tr" || rename_ident == "move_tr" {
+            } else if is_tr_macro_name(&rename_ident) {
                 self.errors.push(format!(
                     "Importing as `{rename_ident}` is not allowed because breaks leptos-fluent's compile-time checking of translations."
                 ));
@@ -785,9 +984,7 @@ impl<'ast> Visit<'ast> for TranslationsMacrosVisitor<'_> {
 
     fn visit_use_name(&mut self, node: &'ast syn::UseName) {
         let ident = node.ident.to_string();
-        if !self.current_use_path_is_leptos_fluent
-            && (ident == "tr" || ident == "move_tr")
-        {
+        if !self.current_use_path_is_leptos_fluent && is_tr_macro_name(&ident) {
             self.errors.push(format!(
                 "Importing `{ident}` is not allowed because breaks leptos-fluent's compile-time checking of translations."
             ));
@@ -813,8 +1010,13 @@ fn value_from_literal_str<'a>(
     } else {
         // TODO: Indicate the source file, line and column on nightly
         // https://doc.rust-lang.org/beta/proc_macro/struct.Span.html#method.source_file
+        let location = if location_macro_name == TR_COMPONENT_NAME {
+            format!("`<{location_macro_name}/>` component")
+        } else {
+            format!("`{location_macro_name}!` macro")
+        };
         Err(format!(
-            "Literal `{literal_str}` at `{location_macro_name}!` macro must be a string literal"
+            "Literal `{literal_str}` at {location} must be a string literal"
         ))
     }
 }
@@ -847,6 +1049,17 @@ mod tests {
                 name: $name.to_string(),
                 message_name: $message_name.to_string(),
                 placeables: $placeables,
+                namespace: None,
+                #[cfg(feature = "nightly")]
+                start: proc_macro2::LineColumn { line: 0, column: 0 },
+            }
+        };
+        (ns = $ns:literal, $name:literal, $message_name:literal, $placeables:expr) => {
+            TranslationMacro {
+                name: $name.to_string(),
+                message_name: $message_name.to_string(),
+                placeables: $placeables,
+                namespace: Some($ns.to_string()),
                 #[cfg(feature = "nightly")]
                 start: proc_macro2::LineColumn { line: 0, column: 0 },
             }
@@ -879,6 +1092,33 @@ mod tests {
         assert_eq!(errors, Vec::<String>::new());
     }
 
+    #[test]
+    fn fallible() {
+        let content = quote! {
+            fn App() -> impl IntoView {
+                let banner = try_tr!("marketing-banner");
+                view! {
+                    <p>{try_move_tr!("discount", { "percent" => 20 })}</p>
+                }
+            }
+        };
+        let (tr_macros, errors) = parse_file_content(&content.to_string());
+
+        assert_eq!(
+            tr_macros,
+            vec![
+                tr_macro!("try_tr", "marketing-banner", Vec::new()),
+                tr_macro!(
+                    "try_move_tr",
+                    "discount",
+                    vec!["percent".to_string()]
+                ),
+            ]
+        );
+        assert!(tr_macros.iter().all(TranslationMacro::is_fallible));
+        assert_eq!(errors, Vec::<String>::new());
+    }
+
     #[test]
     fn closure() {
         let content = quote! {
@@ -1724,4 +1964,90 @@ mod tests {
         );
         assert!(errors.is_empty());
     }
+
+    #[test]
+    fn tr_macros_options() {
+        let content = quote! {
+            fn App() -> impl IntoView {
+                let i18n = expect_context::<I18n>();
+                view! {
+                    <p>{tr!(use_isolating = false, "foo")}</p>
+                    <input value=move_tr!(use_isolating = false, "bar", { "name" => name }) />
+                    <p>{tr!(use_isolating = true, i18n, "baz", { "count" => 1 })}</p>
+                }
+            }
+        };
+        let (tr_macros, errors) = parse_file_content(&content.to_string());
+
+        assert_eq!(
+            tr_macros,
+            vec![
+                tr_macro!("tr", "foo", Vec::new()),
+                tr_macro!("move_tr", "bar", vec!["name".to_string()]),
+                tr_macro!("tr", "baz", vec!["count".to_string()]),
+            ]
+        );
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn tr_macros_namespace() {
+        let content = quote! {
+            fn App() -> impl IntoView {
+                view! {
+                    <p>{tr!("foo")}</p>
+                    <p>{tr!(ns = "widget", "foo")}</p>
+                    <p>{move_tr!(ns = "widget", "bar", { "name" => name })}</p>
+                    <p>{tr!(use_isolating = false, ns = "widget", "baz")}</p>
+                }
+            }
+        };
+        let (tr_macros, errors) = parse_file_content(&content.to_string());
+
+        assert_eq!(
+            tr_macros,
+            vec![
+                tr_macro!("tr", "foo", Vec::new()),
+                tr_macro!(ns = "widget", "tr", "foo", Vec::new()),
+                tr_macro!(
+                    ns = "widget",
+                    "move_tr",
+                    "bar",
+                    vec!["name".to_string()]
+                ),
+                tr_macro!(ns = "widget", "tr", "baz", Vec::new()),
+            ]
+        );
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn tr_components() {
+        let content = quote! {
+            fn App() -> impl IntoView {
+                let name = RwSignal::new("Jane".to_string());
+                view! {
+                    <Tr id="foo" />
+                    <Tr id="bar" args=vec![("name", name.into()), ("count", 3.into())] tag="p" />
+                    <Tr attr:class="baz" ns="widget" args=[("name", name.into())] id="baz" />
+                    <Trait id="qux" />
+                }
+            }
+        };
+        let (tr_macros, errors) = parse_file_content(&content.to_string());
+
+        assert_eq!(
+            tr_macros,
+            vec![
+                tr_macro!("Tr", "foo", Vec::new()),
+                tr_macro!(
+                    "Tr",
+                    "bar",
+                    vec!["name".to_string(), "count".to_string()]
+                ),
+                tr_macro!(ns = "widget", "Tr", "baz", vec!["name".to_string()]),
+            ]
+        );
+        assert!(errors.is_empty());
+    }
 }
@@ -1,10 +1,14 @@
-use crate::fluent_entries::FluentEntries;
-use crate::tr_macros::TranslationMacro;
+use crate::fluent_entries::{
+    FluentEntries, FluentFunctionCalls, ROUTE_MESSAGE_PREFIX,
+};
+use crate::tr_macros::{TranslationMacro, TR_COMPONENT_NAME};
 
 #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
 pub(crate) fn run(
     fluent_entries: &FluentEntries,
     tr_macros: &[TranslationMacro],
+    fluent_function_calls: &FluentFunctionCalls,
+    known_functions: &[String],
 ) -> Vec<String> {
     let mut check_messages =
         check_tr_macros_against_fluent_entries(tr_macros, fluent_entries);
@@ -12,6 +16,10 @@ pub(crate) fn run(
         tr_macros,
         fluent_entries,
     ));
+    check_messages.extend(check_fluent_function_calls_are_known(
+        fluent_function_calls,
+        known_functions,
+    ));
 
     // TODO: Currently, the fluent-syntax parser does not offer a CST
     //       parser so we don't know the spans of the entries.
@@ -67,6 +75,15 @@ fn check_tr_macros_against_fluent_entries(
     let mut error_messages: Vec<String> = Vec::new();
 
     for tr_macro in tr_macros {
+        // fallible macros can reference messages not defined for some
+        // languages, but must be defined at least for one
+        let skip_missing_message = tr_macro.is_fallible()
+            && fluent_entries.values().any(|entries| {
+                entries
+                    .iter()
+                    .any(|entry| entry.message_name == tr_macro.message_name)
+            });
+
         for (lang, entries) in fluent_entries {
             // tr macro message must be defined for each language
             let mut message_name_found = false;
@@ -98,9 +115,14 @@ fn check_tr_macros_against_fluent_entries(
                 }
             }
             if !message_name_found {
-                let error_message = if check_tr_macro_message_name_is_valid(
-                    &tr_macro.message_name,
-                ) {
+                let message_name_is_valid =
+                    check_tr_macro_message_name_is_valid(
+                        &tr_macro.message_name,
+                    );
+                if message_name_is_valid && skip_missing_message {
+                    continue;
+                }
+                let error_message = if message_name_is_valid {
                     format!(
                         concat!(
                             r#"Message "{}" defined at {} macro call in {}"#,
@@ -117,7 +139,9 @@ fn check_tr_macros_against_fluent_entries(
                             r#"Invalid message identifier "{}" defined at"#,
                             r#" {} macro call in {} for locale "{}"."#,
                             " Fluent message identifiers must match the",
-                            " regular expression '[a-zA-Z][a-zA-Z0-9_-]+'.",
+                            " regular expression '[a-zA-Z][a-zA-Z0-9_-]+',",
+                            " optionally followed by an attribute",
+                            " identifier separated by a dot.",
                         ),
                         tr_macro.message_name,
                         format_macro_call(tr_macro),
@@ -170,7 +194,11 @@ fn check_fluent_entries_against_tr_macros(
                     break;
                 }
             }
-            if !message_name_found {
+            // route slugs are used by `leptos_fluent::routes`
+            if !message_name_found
+                && !(cfg!(feature = "router")
+                    && entry.message_name.starts_with(ROUTE_MESSAGE_PREFIX))
+            {
                 let error_message = format!(
                     concat!(
                         r#"Message "{}" of locale "{}" not found in any"#,
@@ -185,21 +213,65 @@ fn check_fluent_entries_against_tr_macros(
     error_messages
 }
 
+fn check_fluent_function_calls_are_known(
+    fluent_function_calls: &FluentFunctionCalls,
+    known_functions: &[String],
+) -> Vec<String> {
+    let mut error_messages: Vec<String> = Vec::new();
+
+    let mut langs = fluent_function_calls.keys().collect::<Vec<_>>();
+    langs.sort();
+    for lang in langs {
+        for (message_name, function) in &fluent_function_calls[lang] {
+            if !known_functions.contains(function) {
+                error_messages.push(format!(
+                    concat!(
+                        r#"Unknown function "{}" called in message "{}" of"#,
+                        r#" locale "{}". Register it with the `functions`"#,
+                        " parameter of `leptos_fluent!`, declare its name",
+                        " there if it's installed by other means or enable",
+                        " the feature that provides it.",
+                    ),
+                    function, message_name, lang,
+                ));
+            }
+        }
+    }
+    error_messages
+}
+
 fn format_macro_call(tr_macro: &TranslationMacro) -> String {
     let macro_name = &tr_macro.name;
     let message_name = &tr_macro.message_name;
+    if macro_name == TR_COMPONENT_NAME {
+        if !tr_macro.placeables.is_empty() {
+            return format!(r#"`<Tr id="{message_name}" args=... />`"#);
+        }
+        return format!(r#"`<Tr id="{message_name}" />`"#);
+    }
     if !tr_macro.placeables.is_empty() {
         return format!(r#"`{macro_name}!("{message_name}", {{ ... }})`"#);
     }
     format!(r#"`{macro_name}!("{message_name}")`"#)
 }
 
-/// Check if the message name is a valid Fluent message identifier.
+/// Check if the message name is a valid Fluent message identifier,
+/// optionally followed by an attribute identifier (`message.attribute`).
 ///
 /// See the Fluent EBNF grammar for message identifiers:
 /// https://github.com/projectfluent/fluent/blob/fd8f95478e29dda8121da7e275d375eb8dadbcb0/spec/fluent.ebnf
 fn check_tr_macro_message_name_is_valid(message_name: &str) -> bool {
-    let mut chars = message_name.chars();
+    match message_name.split_once('.') {
+        Some((message_id, attribute_id)) => {
+            check_identifier_is_valid(message_id)
+                && check_identifier_is_valid(attribute_id)
+        }
+        None => check_identifier_is_valid(message_name),
+    }
+}
+
+fn check_identifier_is_valid(identifier: &str) -> bool {
+    let mut chars = identifier.chars();
     if !chars.next().unwrap_or('0').is_ascii_alphabetic() {
         return false;
     }
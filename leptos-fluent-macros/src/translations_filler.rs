@@ -8,6 +8,7 @@ use std::rc::Rc;
 #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
 pub(crate) fn run(
     globstr: &str,
+    namespace: Option<&str>,
     manifest_path: impl AsRef<Path>,
     fluent_entries: &FluentEntries,
     fluent_file_paths: &FluentFilePaths,
@@ -30,8 +31,13 @@ pub(crate) fn run(
 
     let mut missing_message_names_by_lang: HashMap<Rc<String>, Vec<String>> =
         HashMap::new();
+    // messages of fallible macros are not required to be defined
     let tr_macros_message_names = tr_macros
         .iter()
+        .filter(|tr_macro| !tr_macro.is_fallible())
+        .filter(|tr_macro| tr_macro.namespace.as_deref() == namespace)
+        // attributes can't be filled without their message
+        .filter(|tr_macro| !tr_macro.message_name.contains('.'))
         .map(|tr_macro| tr_macro.message_name.clone())
         .collect::<Vec<String>>();
 
@@ -0,0 +1,24 @@
+use leptos::prelude::*;
+use leptos_fluent_macros::leptos_fluent;
+
+#[component]
+fn I18n(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../examples/csr-minimal/locales",
+        fallback_languages: {
+            "es" => ["foobarbaz"],
+        },
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <I18n>
+            <p>+</p>
+        </I18n>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use leptos::prelude::*;
+use leptos_fluent::__reexports::fluent_templates::fluent_bundle::{
+    FluentArgs, FluentValue,
+};
+use leptos_fluent_macros::leptos_fluent;
+
+fn shout<'a>(positional: &[FluentValue<'a>], _: &FluentArgs) -> FluentValue<'a> {
+    positional.first().cloned().unwrap_or(FluentValue::Error)
+}
+
+#[component]
+fn I18n(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../examples/csr-minimal/locales",
+        functions: {
+            "shout" => shout,
+        },
+    }
+}
+
+fn main() {}
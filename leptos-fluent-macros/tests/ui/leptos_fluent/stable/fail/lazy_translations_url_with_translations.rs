@@ -0,0 +1,31 @@
+use leptos::prelude::*;
+use leptos_fluent::__reexports::fluent_templates::{self, static_loader};
+use leptos_fluent_macros::leptos_fluent;
+
+static_loader! {
+    static TRANSLATIONS = {
+        locales: "../../../../examples/csr-minimal/locales",
+        fallback_language: "en",
+    };
+}
+
+#[component]
+fn I18n(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../examples/csr-minimal/locales",
+        translations: [TRANSLATIONS],
+        lazy_translations_url: "/locales",
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <I18n>
+            <p>+</p>
+        </I18n>
+    }
+}
+
+fn main() {}
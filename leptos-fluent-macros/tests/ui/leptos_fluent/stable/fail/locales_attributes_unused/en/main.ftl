@@ -0,0 +1,3 @@
+login-input = Username
+    .placeholder = Enter your username
+    .aria-label = Login
@@ -0,0 +1,2 @@
+hello-world = Hello, world!
+hello_world = Hello, world!
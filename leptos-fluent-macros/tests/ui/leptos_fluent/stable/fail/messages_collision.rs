@@ -0,0 +1,7 @@
+mod msgs {
+    leptos_fluent::messages! {
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/fail/locales_messages_collision",
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use leptos::prelude::*;
+use leptos_fluent::components::Tr;
+use leptos_fluent_macros::leptos_fluent;
+
+#[component]
+fn I18n(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/fail/locales_tr_component",
+        check_translations: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/fail/tr_component_unknown_variable.rs",
+    }
+}
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <Tr id="widget-title" args=vec![("count", 3.into())] />
+        <Tr id="widget-greeting" />
+    }
+}
+
+fn main() {}
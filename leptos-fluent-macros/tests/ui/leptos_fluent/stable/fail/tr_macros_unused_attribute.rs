@@ -0,0 +1,22 @@
+use leptos::prelude::*;
+use leptos_fluent::move_tr;
+use leptos_fluent_macros::leptos_fluent;
+
+#[component]
+fn I18n(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/fail/locales_attributes_unused",
+        check_translations: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/fail/tr_macros_unused_attribute.rs",
+    }
+}
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <label>{move_tr!("login-input")}</label>
+        <input placeholder=move_tr!("login-input.placeholder") />
+    }
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use leptos::prelude::*;
+use leptos_fluent::try_tr;
+use leptos_fluent_macros::leptos_fluent;
+
+#[component]
+fn I18n(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/fail/locales_tr_component",
+        check_translations: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/fail/try_tr_macros_unknown_message.rs",
+    }
+}
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <p>{move || try_tr!("widget-title")}</p>
+        <p>{move || try_tr!("widget-greeting", { "name" => "Jane" })}</p>
+        <p>{move || try_tr!("marketing-banner")}</p>
+    }
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use leptos::prelude::*;
+use leptos_fluent::tr;
+use leptos_fluent_macros::leptos_fluent;
+
+#[component]
+fn I18n(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_functions",
+        check_translations: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/fail/unknown_function.rs",
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <I18n>
+            <p>{tr!("warning", { "text" => "careful" })}</p>
+            <p>{tr!("quiet", { "text" => "careful" })}</p>
+        </I18n>
+    }
+}
+
+fn main() {}
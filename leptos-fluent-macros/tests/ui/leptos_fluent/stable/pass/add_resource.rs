@@ -0,0 +1,60 @@
+// Resources added at runtime take precedence over static translations.
+use leptos::prelude::*;
+use leptos_fluent::__reexports::fluent_templates::{self, static_loader};
+use leptos_fluent::{AddResourceError, I18n, Language, WritingDirection};
+
+static_loader! {
+    static TRANSLATIONS = {
+        locales: "../../../../examples/csr-minimal/locales",
+        fallback_language: "en",
+    };
+}
+
+static EN: Language = Language::new("en", "English", &WritingDirection::Ltr);
+
+static ES: Language = Language::new("es", "Español", &WritingDirection::Ltr);
+
+static LANGUAGES: &[&Language] = &[&EN, &ES];
+
+fn main() {
+    Owner::new().with(|| {
+        let i18n = I18n::new(
+            RwSignal::new(&ES),
+            LANGUAGES,
+            Signal::stored(vec![&TRANSLATIONS]),
+        );
+        let select_a_language =
+            Signal::derive(move || i18n.tr("select-a-language"));
+        assert_eq!(select_a_language.get(), "Selecciona un idioma:");
+
+        i18n.add_resource(
+            "es",
+            "select-a-language = Elige un idioma:".to_string(),
+        )
+        .unwrap();
+        assert_eq!(select_a_language.get(), "Elige un idioma:");
+
+        i18n.add_resource(
+            "en",
+            "promo = Summer sales!\n    .title = Sales for { $name }"
+                .to_string(),
+        )
+        .unwrap();
+        assert_eq!(i18n.tr("promo"), "Summer sales!");
+        let mut args = std::collections::HashMap::new();
+        args.insert("name".into(), "you".into());
+        assert_eq!(
+            i18n.tr_with_args("promo.title", &args),
+            "Sales for \u{2068}you\u{2069}"
+        );
+
+        assert!(matches!(
+            i18n.add_resource("es", "invalid message".to_string()),
+            Err(AddResourceError::Parse(_))
+        ));
+        assert!(matches!(
+            i18n.add_resource("-", String::new()),
+            Err(AddResourceError::InvalidLanguage(_))
+        ));
+    });
+}
@@ -0,0 +1,40 @@
+// Names of the languages in the current language.
+use leptos::prelude::*;
+use leptos_fluent::__reexports::fluent_templates::{self, static_loader};
+use leptos_fluent::{I18n, Language, WritingDirection};
+
+static_loader! {
+    static TRANSLATIONS = {
+        locales: "../../../../examples/csr-minimal/locales",
+        fallback_language: "en",
+    };
+}
+
+static EN: Language = Language::new("en", "English", &WritingDirection::Ltr)
+    .with_display_names(&[("es", "Inglés")]);
+
+static ES: Language = Language::new("es", "Español", &WritingDirection::Ltr)
+    .with_display_names(&[("en", "Spanish")]);
+
+static FR: Language = Language::new("fr", "Français", &WritingDirection::Ltr);
+
+static LANGUAGES: &[&Language] = &[&EN, &ES, &FR];
+
+fn main() {
+    assert_eq!(ES.name_in(&EN), "Spanish");
+    assert_eq!(EN.name_in(&ES), "Inglés");
+    assert_eq!(EN.name_in(&EN), "English");
+    assert_eq!(FR.name_in(&EN), "Français");
+
+    Owner::new().with(|| {
+        let i18n = I18n::new(
+            RwSignal::new(&EN),
+            LANGUAGES,
+            Signal::stored(vec![&TRANSLATIONS]),
+        );
+        let spanish = Signal::derive(move || i18n.language_display_name(&ES));
+        assert_eq!(spanish.get(), "Spanish");
+        i18n.language.set(&ES);
+        assert_eq!(spanish.get(), "Español");
+    });
+}
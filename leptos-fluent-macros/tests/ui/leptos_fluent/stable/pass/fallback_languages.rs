@@ -0,0 +1,47 @@
+// Messages missing in a language are resolved in its fallback chain.
+use leptos::prelude::*;
+use leptos_fluent::{leptos_fluent, move_tr, tr};
+
+#[component]
+fn I18nProvider(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_fallbacks",
+        default_language: "es-MX",
+        fallback_languages: {
+            "es-MX" => ["es", "en"],
+            "es" => ["en"],
+        },
+    }
+}
+
+#[component]
+fn Messages() -> impl IntoView {
+    let i18n = expect_context::<leptos_fluent::I18n>();
+    assert_eq!(i18n.language.get().id, "es-MX");
+    assert_eq!(tr!("hello"), "¡Quiubo!");
+    assert_eq!(tr!("goodbye"), "¡Adiós!");
+    assert_eq!(tr!("thanks"), "Thanks!");
+
+    let spanish = i18n.languages.iter().find(|lang| lang.id == "es").unwrap();
+    i18n.language.set(spanish);
+    assert_eq!(tr!("hello"), "¡Hola!");
+    assert_eq!(tr!("goodbye"), "¡Adiós!");
+    assert_eq!(tr!("thanks"), "Thanks!");
+
+    view! {
+        <p>{move_tr!("hello")}</p>
+        <p>{move_tr!("goodbye")}</p>
+        <p>{move_tr!("thanks")}</p>
+    }
+}
+
+fn main() {
+    Owner::new().with(|| {
+        _ = view! {
+            <I18nProvider>
+                <Messages/>
+            </I18nProvider>
+        };
+    });
+}
@@ -0,0 +1,135 @@
+// Functions of the `functions` parameter are installed in the bundles of the
+// context, including the ones built from the sources of the loaders of
+// `leptos_fluent::static_loader!` passed with `translations`. Other loaders
+// install them in their `customise` closure. Functions installed by other
+// means are declared by name.
+use leptos::prelude::*;
+use leptos_fluent::__reexports::fluent_templates::{
+    self,
+    fluent_bundle::{FluentArgs, FluentValue},
+    static_loader, Loader,
+};
+use leptos_fluent::{tr, I18n};
+use leptos_fluent_macros::leptos_fluent;
+
+fn shout<'a>(
+    positional: &[FluentValue<'a>],
+    _: &FluentArgs,
+) -> FluentValue<'a> {
+    match positional.first() {
+        Some(FluentValue::String(s)) => s.to_uppercase().into(),
+        _ => FluentValue::Error,
+    }
+}
+
+fn whisper<'a>(
+    positional: &[FluentValue<'a>],
+    _: &FluentArgs,
+) -> FluentValue<'a> {
+    match positional.first() {
+        Some(FluentValue::String(s)) => s.to_lowercase().into(),
+        _ => FluentValue::Error,
+    }
+}
+
+static_loader! {
+    static TRANSLATIONS = {
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_functions",
+        fallback_language: "en",
+        customise: |bundle| leptos_fluent::functions::add_functions_with(
+            bundle,
+            &[("SHOUT", shout)],
+        ),
+    };
+}
+
+leptos_fluent::static_loader! {
+    static CONTEXT_TRANSLATIONS = {
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_functions",
+        fallback_language: "en",
+    };
+}
+
+fn main() {
+    // loaders install their functions even when built before any context
+    let mut args = std::collections::HashMap::new();
+    args.insert("text".into(), "careful".into());
+    assert_eq!(
+        TRANSLATIONS.lookup_with_args(&"en".parse().unwrap(), "warning", &args),
+        "\u{2068}CAREFUL\u{2069}!"
+    );
+
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_functions",
+            default_language: "es",
+            check_translations: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/functions.rs",
+            functions: {
+                "SHOUT" => shout,
+                "WHISPER",
+            },
+            customise: |bundle| {
+                _ = bundle.add_function("WHISPER", whisper);
+            },
+        };
+        let i18n = expect_context::<I18n>();
+        assert_eq!(tr!("warning", { "text" => "cuidado" }), "¡\u{2068}CUIDADO\u{2069}!");
+        assert_eq!(tr!("quiet", { "text" => "CUIDADO" }), "cuidado");
+
+        i18n.add_resource("en", "runtime = { SHOUT(\"hi\") }".to_string())
+            .unwrap();
+        i18n.language
+            .set(i18n.languages.iter().find(|lang| lang.id == "en").unwrap());
+        assert_eq!(i18n.tr("runtime"), "HI");
+    });
+
+    // loaders of `fluent_templates::static_loader!` are formatted by their
+    // own bundles
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            translations: [TRANSLATIONS],
+            locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_functions",
+            default_language: "en",
+        };
+        assert_eq!(
+            tr!("warning", { "text" => "careful" }),
+            "\u{2068}CAREFUL\u{2069}!"
+        );
+    });
+
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            translations: [CONTEXT_TRANSLATIONS],
+            locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_functions",
+            default_language: "en",
+            functions: {
+                "SHOUT" => shout,
+            },
+        };
+        assert_eq!(
+            tr!("warning", { "text" => "careful" }),
+            "\u{2068}CAREFUL\u{2069}!"
+        );
+    });
+
+    // functions are not shared between contexts
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            translations: [CONTEXT_TRANSLATIONS],
+            locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_functions",
+            default_language: "en",
+        };
+        let i18n = expect_context::<I18n>();
+        assert_ne!(
+            tr!("warning", { "text" => "careful" }),
+            "\u{2068}CAREFUL\u{2069}!"
+        );
+        i18n.add_resource("en", "runtime = { SHOUT(\"hi\") }".to_string())
+            .unwrap();
+        assert_ne!(i18n.tr("runtime"), "HI");
+    });
+}
@@ -0,0 +1,63 @@
+// Alternate links for search engines rendered with `sync_hreflang_links`
+// and with the `HreflangLinks` component.
+use leptos::prelude::*;
+use leptos_fluent::components::HreflangLinks;
+use leptos_fluent::{leptos_fluent, I18n};
+
+const ORIGIN: &str = "https://example.com";
+
+#[component]
+fn I18nProvider(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../examples/csr-minimal/locales",
+    }
+}
+
+#[component]
+fn SyncedI18nProvider(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../examples/csr-minimal/locales",
+        url_param: "language",
+        sync_hreflang_links: true,
+        hreflang_origin: "https://example.com",
+    }
+}
+
+#[component]
+fn NamespacedSyncedI18nProvider(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../examples/csr-minimal/locales",
+        namespace: "widget",
+        sync_hreflang_links: cfg!(feature = "ssr"),
+        hreflang_origin: ORIGIN,
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <SyncedI18nProvider>
+            <NamespacedSyncedI18nProvider>
+                <p></p>
+            </NamespacedSyncedI18nProvider>
+        </SyncedI18nProvider>
+    }
+}
+
+fn main() {
+    Owner::new().with(|| {
+        _ = view! {
+            <I18nProvider>
+                <HreflangLinks origin="https://example.com" path="/about?lang=es&page=2" />
+                <HreflangLinks path="/es/about" url_path=true />
+                {
+                    let i18n = expect_context::<I18n>();
+                    assert_eq!(i18n.languages.len(), 2);
+                }
+            </I18nProvider>
+        };
+    });
+}
@@ -0,0 +1,57 @@
+// Subtrees rendered in other languages with `<LanguageScope/>`.
+use leptos::prelude::*;
+use leptos_fluent::{components::LanguageScope, leptos_fluent, tr, I18n};
+
+#[component]
+fn I18nProvider(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../examples/csr-minimal/locales",
+    }
+}
+
+#[component]
+fn Quotes(translations: StoredValue<Vec<String>>) -> impl IntoView {
+    let i18n = expect_context::<I18n>();
+    let spanish = i18n.languages.iter().find(|lang| lang.id == "es").unwrap();
+
+    // the derived context doesn't change the language of the application
+    let scoped = i18n.scope(spanish);
+    assert_eq!(scoped.tr("select-a-language"), "Selecciona un idioma:");
+    scoped.language.set(i18n.languages[0]);
+    assert_eq!(scoped.tr("select-a-language"), "Select a language:");
+    scoped.language.set(spanish);
+    assert_eq!(i18n.language.get().id, "en");
+
+    let record = move || {
+        translations.update_value(|t| t.push(tr!("select-a-language")))
+    };
+    view! {
+        {record()}
+        <LanguageScope lang=*spanish tag="blockquote">
+            {record()}
+            <LanguageScope lang=i18n.languages[0]>{record()}</LanguageScope>
+        </LanguageScope>
+        {record()}
+    }
+}
+
+fn main() {
+    Owner::new().with(|| {
+        let translations = StoredValue::new(vec![]);
+        _ = view! {
+            <I18nProvider>
+                <Quotes translations/>
+            </I18nProvider>
+        };
+        assert_eq!(
+            translations.get_value(),
+            [
+                "Select a language:",
+                "Selecciona un idioma:",
+                "Select a language:",
+                "Select a language:",
+            ]
+        );
+    });
+}
@@ -0,0 +1,46 @@
+// Language selectors provided by `leptos_fluent::components`.
+use leptos::prelude::*;
+use leptos_fluent::components::{
+    LanguageLinks, LanguageRadioGroup, LanguageSelect,
+};
+use leptos_fluent::{leptos_fluent, move_tr, I18n};
+
+#[component]
+fn I18nProvider(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../examples/csr-minimal/locales",
+        check_translations: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/language_selectors.rs",
+    }
+}
+
+#[component]
+fn Selectors() -> impl IntoView {
+    view! {
+        <LanguageSelect />
+        <LanguageSelect label=move_tr!("select-a-language") flags=true />
+        <LanguageRadioGroup />
+        <LanguageRadioGroup legend=move_tr!("select-a-language") name="lang" flags=true />
+        <LanguageLinks />
+        <LanguageLinks
+            label=move_tr!("language-is-english")
+            href=|lang| format!("/{}/", lang.id)
+            flags=true
+        />
+        <p>{move_tr!("language-is-spanish")}</p>
+    }
+}
+
+fn main() {
+    Owner::new().with(|| {
+        _ = view! {
+            <I18nProvider>
+                <Selectors />
+                {
+                    let i18n = expect_context::<I18n>();
+                    assert_eq!(i18n.language.get().id, "en");
+                }
+            </I18nProvider>
+        };
+    });
+}
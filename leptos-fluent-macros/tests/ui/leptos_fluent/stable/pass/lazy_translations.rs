@@ -0,0 +1,83 @@
+// Only the default language is embedded and the rest of languages are
+// fetched from `/locales/{lang}/{file}` when the language changes.
+use any_spawner::{CustomExecutor, Executor, PinnedFuture, PinnedLocalFuture};
+use leptos::prelude::*;
+use leptos_fluent::{leptos_fluent, move_tr, tr, I18n};
+
+#[component]
+fn I18nProvider(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../examples/csr-minimal/locales",
+        default_language: "es",
+        lazy_translations_url: "/locales",
+    }
+}
+
+#[component]
+fn Page() -> impl IntoView {
+    let i18n = expect_context::<I18n>();
+
+    view! {
+        <Suspense fallback=|| "Loading...">
+            {move || i18n.lazy_translations().map(|resource| resource.get())}
+            <p>{move_tr!("select-a-language")}</p>
+        </Suspense>
+        <Show when=move || i18n.loading().get()>
+            <p>"Loading translations..."</p>
+        </Show>
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <I18nProvider>
+            <Page />
+        </I18nProvider>
+    }
+}
+
+// There is no browser to fetch translations from, so the futures that fetch
+// them are kept pending.
+struct PendingExecutor;
+
+impl CustomExecutor for PendingExecutor {
+    fn spawn(&self, _fut: PinnedFuture<()>) {}
+    fn spawn_local(&self, _fut: PinnedLocalFuture<()>) {}
+    fn poll_local(&self) {}
+}
+
+fn main() {
+    Executor::init_custom_executor(PendingExecutor).unwrap();
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            locales: "../../../../examples/csr-minimal/locales",
+            default_language: "es",
+            lazy_translations_url: "/locales",
+        };
+        let i18n = expect_context::<I18n>();
+        assert!(i18n.lazy_translations().is_some());
+        assert!(!i18n.loading().get_untracked());
+        assert_eq!(tr!("select-a-language"), "Selecciona un idioma:");
+
+        // scoped contexts fetch the translations of their language with
+        // their own resource
+        let english = i18n.languages.iter().find(|l| l.id == "en").unwrap();
+        let scoped = i18n.scope(english);
+        assert!(scoped.lazy_translations().is_some());
+
+        // languages not fetched yet fallback to the embedded one
+        i18n.language.set(english);
+        assert_eq!(tr!("select-a-language"), "Selecciona un idioma:");
+
+        // fetched translations are added as resources of their language
+        i18n.add_resource(
+            "en",
+            "select-a-language = Select a language:".to_string(),
+        )
+        .unwrap();
+        assert_eq!(tr!("select-a-language"), "Select a language:");
+    });
+}
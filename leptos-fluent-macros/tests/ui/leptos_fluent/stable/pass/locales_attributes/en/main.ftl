@@ -0,0 +1,3 @@
+login-input = Username
+    .placeholder = Enter { $name }
+    .aria-label = Login
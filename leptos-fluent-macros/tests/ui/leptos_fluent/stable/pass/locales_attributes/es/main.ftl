@@ -0,0 +1,3 @@
+login-input = Usuario
+    .placeholder = Introduce { $name }
+    .aria-label = Acceder
@@ -0,0 +1,3 @@
+hello = Hello!
+goodbye = Goodbye!
+thanks = Thanks!
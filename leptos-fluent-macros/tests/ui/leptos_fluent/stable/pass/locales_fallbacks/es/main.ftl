@@ -0,0 +1,2 @@
+hello = ¡Hola!
+goodbye = ¡Adiós!
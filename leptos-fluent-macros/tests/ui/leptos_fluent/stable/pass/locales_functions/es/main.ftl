@@ -0,0 +1,2 @@
+warning = ¡{ SHOUT($text) }!
+quiet = { WHISPER($text) }
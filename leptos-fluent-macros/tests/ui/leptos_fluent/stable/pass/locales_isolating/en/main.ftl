@@ -0,0 +1,2 @@
+greeting = Hello, { $name }!
+quoted = {"\u2068"}Jane{"\u2069"}
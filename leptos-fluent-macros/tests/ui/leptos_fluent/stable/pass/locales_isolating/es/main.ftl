@@ -0,0 +1,2 @@
+greeting = ¡Hola, { $name }!
+quoted = {"\u2068"}Juan{"\u2069"}
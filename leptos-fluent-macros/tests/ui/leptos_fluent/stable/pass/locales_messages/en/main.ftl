@@ -0,0 +1,5 @@
+# Greeting shown in the header.
+hello-args = Hello, { $arg1 } and { $arg2 }!
+simple = Simple
+login-input = Username
+    .placeholder = Enter { $user-name }
@@ -0,0 +1,5 @@
+# Saludo mostrado en la cabecera.
+hello-args = ¡Hola, { $arg1 } y { $arg2 }!
+simple = Simple
+login-input = Usuario
+    .placeholder = Introduce { $user-name }
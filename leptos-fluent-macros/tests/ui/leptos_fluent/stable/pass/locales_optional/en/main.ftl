@@ -0,0 +1,3 @@
+title = Store
+marketing-banner = Summer sale!
+discount = { $percent }% off
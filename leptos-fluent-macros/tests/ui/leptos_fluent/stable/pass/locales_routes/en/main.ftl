@@ -0,0 +1 @@
+pricing-title = Pricing
@@ -0,0 +1,6 @@
+pricing-title = Precios
+route-pricing = precios
+route-plans = planes
+route-categories = categorías
+-store = tienda
+route-store = { -store }-en-linea
@@ -0,0 +1,2 @@
+widget-title = Widget
+widget-greeting = Hello, { $name }!
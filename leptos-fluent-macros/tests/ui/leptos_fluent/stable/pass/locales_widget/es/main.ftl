@@ -0,0 +1,2 @@
+widget-title = Componente
+widget-greeting = ¡Hola, { $name }!
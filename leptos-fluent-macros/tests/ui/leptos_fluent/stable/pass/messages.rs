@@ -0,0 +1,47 @@
+// Typed functions are generated for each message of the FTL files.
+use leptos::prelude::*;
+use leptos_fluent::__reexports::fluent_templates::{self, static_loader};
+use leptos_fluent::{I18n, Language, WritingDirection};
+
+mod msgs {
+    leptos_fluent::messages! {
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_messages",
+    }
+}
+
+static_loader! {
+    static TRANSLATIONS = {
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_messages",
+        fallback_language: "en",
+        customise: |bundle| bundle.set_use_isolating(false),
+    };
+}
+
+static EN: Language = Language::new("en", "English", &WritingDirection::Ltr);
+
+static ES: Language = Language::new("es", "Español", &WritingDirection::Ltr);
+
+static LANGUAGES: &[&Language] = &[&EN, &ES];
+
+fn main() {
+    Owner::new().with(|| {
+        let i18n = I18n::new(
+            RwSignal::new(&EN),
+            LANGUAGES,
+            Signal::stored(vec![&TRANSLATIONS]),
+        );
+
+        assert_eq!(msgs::simple(i18n), "Simple");
+        assert_eq!(msgs::login_input(i18n), "Username");
+        assert_eq!(msgs::login_input_placeholder(i18n, "name"), "Enter name");
+
+        let name = RwSignal::new("Jane".to_string());
+        let hello =
+            msgs::hello_args_signal(i18n, move || name.get(), || "John");
+        assert_eq!(hello.get(), "Hello, Jane and John!");
+        name.set("Ana".to_string());
+        i18n.language.set(&ES);
+        assert_eq!(hello.get(), "¡Hola, Ana y John!");
+        assert_eq!(msgs::hello_args(i18n, 1, 2.5), "¡Hola, 1 y 2.5!");
+    });
+}
@@ -0,0 +1,53 @@
+// Cached translations of messages without arguments follow the language
+// and the translations of the context.
+use leptos::prelude::*;
+use leptos_fluent::__reexports::fluent_templates::{self, static_loader};
+use leptos_fluent::{I18n, Language, WritingDirection};
+
+static_loader! {
+    static TRANSLATIONS = {
+        locales: "../../../../examples/csr-minimal/locales",
+        fallback_language: "en",
+    };
+}
+
+static_loader! {
+    static OTHER_TRANSLATIONS = {
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_isolating",
+        fallback_language: "en",
+    };
+}
+
+static EN: Language = Language::new("en", "English", &WritingDirection::Ltr);
+
+static ES: Language = Language::new("es", "Español", &WritingDirection::Ltr);
+
+static LANGUAGES: &[&Language] = &[&EN, &ES];
+
+fn main() {
+    Owner::new().with(|| {
+        let translations = RwSignal::new(vec![&TRANSLATIONS]);
+        let i18n = I18n::new(
+            RwSignal::new(&ES),
+            LANGUAGES,
+            Signal::derive(move || translations.get()),
+        );
+        let select_a_language =
+            Signal::derive(move || i18n.tr("select-a-language"));
+        assert_eq!(select_a_language.get(), "Selecciona un idioma:");
+        assert_eq!(select_a_language.get(), "Selecciona un idioma:");
+
+        i18n.language.set(&EN);
+        assert_eq!(select_a_language.get(), "Select a language:");
+
+        translations.set(vec![&OTHER_TRANSLATIONS]);
+        assert_eq!(
+            select_a_language.get(),
+            "Unknown localization select-a-language"
+        );
+        assert_eq!(i18n.try_tr("select-a-language"), None);
+
+        translations.set(vec![&OTHER_TRANSLATIONS, &TRANSLATIONS]);
+        assert_eq!(select_a_language.get(), "Select a language:");
+    });
+}
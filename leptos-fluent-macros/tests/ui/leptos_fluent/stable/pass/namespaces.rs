@@ -0,0 +1,79 @@
+// Independent contexts provided with the `namespace` parameter.
+use leptos::prelude::*;
+use leptos_fluent::{
+    expect_i18n_namespace, leptos_fluent, move_tr, tr, use_i18n_namespace,
+    I18n,
+};
+
+#[component]
+fn I18nProvider(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../examples/csr-minimal/locales",
+        check_translations: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/namespaces.rs",
+    }
+}
+
+#[component]
+fn WidgetI18nProvider(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        namespace: "widget",
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_widget",
+        check_translations: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/namespaces.rs",
+        default_language: "es",
+    }
+}
+
+#[component]
+fn Widget(translations: StoredValue<Vec<String>>) -> impl IntoView {
+    // the context of the application is not replaced
+    let i18n = expect_context::<I18n>();
+    assert_eq!(i18n.language.get().id, "en");
+    let widget_i18n = expect_i18n_namespace("widget");
+    assert_eq!(widget_i18n.language.get().id, "es");
+    assert!(use_i18n_namespace("other").is_none());
+
+    translations.update_value(|t| {
+        t.push(tr!("select-a-language"));
+        t.push(tr!(ns = "widget", "widget-title"));
+        t.push(tr!(
+            use_isolating = false,
+            ns = "widget",
+            "widget-greeting",
+            { "name" => "Jane" }
+        ));
+    });
+
+    let title = move_tr!(ns = "widget", "widget-title");
+    widget_i18n.language.set(widget_i18n.languages[0]);
+    translations.update_value(|t| t.push(title.get()));
+    assert_eq!(i18n.language.get().id, "en");
+
+    view! {
+        <p>{move_tr!("language-is-english")}</p>
+        <p>{move_tr!("language-is-spanish")}</p>
+    }
+}
+
+fn main() {
+    Owner::new().with(|| {
+        let translations = StoredValue::new(vec![]);
+        _ = view! {
+            <I18nProvider>
+                <WidgetI18nProvider>
+                    <Widget translations/>
+                </WidgetI18nProvider>
+            </I18nProvider>
+        };
+        assert_eq!(
+            translations.get_value(),
+            [
+                "Select a language:",
+                "Componente",
+                "¡Hola, Jane!",
+                "Widget",
+            ]
+        );
+    });
+}
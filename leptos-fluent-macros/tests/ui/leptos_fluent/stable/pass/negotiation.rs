@@ -0,0 +1,89 @@
+// Languages are negotiated with the likely subtags of the CLDR.
+use leptos::prelude::*;
+use leptos_fluent::negotiation::{negotiate_language, NegotiationStrategy};
+use leptos_fluent::{I18n, Language, WritingDirection};
+use leptos_fluent_macros::leptos_fluent;
+
+macro_rules! languages {
+    ($($id:literal),*) => {{
+        static LANGUAGES: &[&Language] = &[$(
+            &Language::new($id, $id, &WritingDirection::Ltr)
+        ),*];
+        LANGUAGES
+    }};
+}
+
+fn negotiate(
+    code: &str,
+    languages: &'static [&'static Language],
+    strategy: NegotiationStrategy,
+) -> Option<&'static str> {
+    negotiate_language(code, languages, strategy).map(|lang| lang.id)
+}
+
+fn main() {
+    let strategy = NegotiationStrategy::Filtering;
+    let chinese = languages!("zh-Hans", "zh-Hant");
+    assert_eq!(negotiate("zh-TW", chinese, strategy), Some("zh-Hant"));
+    assert_eq!(negotiate("zh-HK", chinese, strategy), Some("zh-Hant"));
+    assert_eq!(negotiate("zh-CN", chinese, strategy), Some("zh-Hans"));
+    assert_eq!(negotiate("zh", chinese, strategy), Some("zh-Hans"));
+    let serbian = languages!("sr-Cyrl", "sr-Latn");
+    assert_eq!(negotiate("sr-Latn-RS", serbian, strategy), Some("sr-Latn"));
+    assert_eq!(negotiate("sr", serbian, strategy), Some("sr-Cyrl"));
+    let portuguese = languages!("pt-PT", "pt-BR");
+    assert_eq!(negotiate("pt", portuguese, strategy), Some("pt-BR"));
+    assert_eq!(negotiate("pt-PT", portuguese, strategy), Some("pt-PT"));
+    let english = languages!("es", "en-US", "en-XA");
+    assert_eq!(negotiate("en-AU", english, strategy), Some("en-US"));
+    assert_eq!(negotiate("EN-us", english, strategy), Some("en-US"));
+    assert_eq!(negotiate("en-XA", english, strategy), Some("en-XA"));
+    assert_eq!(negotiate("fr", english, strategy), None);
+    assert_eq!(negotiate("", english, strategy), None);
+
+    let strategy = NegotiationStrategy::Lookup;
+    assert_eq!(negotiate("zh-TW", chinese, strategy), Some("zh-Hant"));
+    assert_eq!(negotiate("zh-CN", chinese, strategy), Some("zh-Hans"));
+    assert_eq!(negotiate("sr-Latn-RS", serbian, strategy), Some("sr-Latn"));
+    assert_eq!(negotiate("pt", portuguese, strategy), Some("pt-BR"));
+    assert_eq!(negotiate("pt-AO", portuguese, strategy), None);
+    let english = languages!("es", "en", "en-US", "en-XA");
+    assert_eq!(negotiate("en-AU", english, strategy), Some("en"));
+    assert_eq!(negotiate("en-US", english, strategy), Some("en-US"));
+    assert_eq!(negotiate("es-MX", english, strategy), Some("es"));
+    assert_eq!(negotiate("fr", english, strategy), None);
+
+    // languages are parsed with the strategy of the context
+    Owner::new().with(|| {
+        let english = languages!("es", "en-US");
+        let i18n = I18n::new(
+            RwSignal::new(english[0]),
+            english,
+            Signal::derive(Vec::new),
+        );
+        provide_context(i18n);
+        assert_eq!(i18n.negotiation_strategy(), NegotiationStrategy::Filtering);
+        assert_eq!(
+            "en-AU".parse::<Language>().map(|lang| lang.id),
+            Ok("en-US")
+        );
+        i18n.set_negotiation_strategy(NegotiationStrategy::Lookup);
+        assert_eq!("en-AU".parse::<Language>().map(|lang| lang.id), Err(()));
+        assert_eq!(
+            "en-US".parse::<Language>().map(|lang| lang.id),
+            Ok("en-US")
+        );
+    });
+
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_fallbacks",
+            default_language: "en",
+            negotiation_strategy: "lookup",
+        };
+        let i18n = expect_context::<I18n>();
+        assert_eq!(i18n.negotiation_strategy(), NegotiationStrategy::Lookup);
+        assert_eq!("es-AR".parse::<Language>().map(|lang| lang.id), Ok("es"));
+    });
+}
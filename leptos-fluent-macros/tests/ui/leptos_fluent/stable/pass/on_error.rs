@@ -0,0 +1,140 @@
+// Missing messages and formatting errors are passed to the `on_error` handler.
+use leptos::prelude::*;
+use leptos_fluent::{
+    leptos_fluent, static_loader, tr, I18n, TranslationError,
+    TranslationErrorKind,
+};
+use std::sync::{Arc, Mutex};
+
+static_loader! {
+    static TRANSLATIONS = {
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_isolating",
+        fallback_language: "en",
+    };
+}
+
+fn on_error(error: &TranslationError) -> String {
+    match error.kind {
+        TranslationErrorKind::MissingMessage => {
+            format!("[{}] {}", error.language.id, error.text_id)
+        }
+        TranslationErrorKind::Format(_) => String::new(),
+    }
+}
+
+fn main() {
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_isolating",
+            default_language: "en",
+            on_error: on_error,
+        };
+        assert_eq!(tr!("missing"), "[en] missing");
+        assert_eq!(tr!("greeting"), "");
+        assert_eq!(
+            tr!("greeting", { "name" => "Jane" }),
+            "Hello, \u{2068}Jane\u{2069}!"
+        );
+    });
+
+    // without handler, messages are displayed as formatted by Fluent
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_isolating",
+            default_language: "en",
+        };
+        assert_eq!(tr!("missing"), "Unknown localization missing");
+        assert_eq!(tr!("greeting"), "Hello, \u{2068}{$name}\u{2069}!");
+    });
+
+    // formatting errors are not resolved in the fallback languages
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_isolating",
+            default_language: "es",
+            fallback_languages: {
+                "es" => ["en"],
+            },
+        };
+        let i18n = expect_context::<I18n>();
+        i18n.add_resource("en", "farewell = Bye!".to_string()).unwrap();
+        i18n.add_resource("es", "farewell = Adiós, { $name }!".to_string())
+            .unwrap();
+        assert_eq!(tr!("farewell"), "Adiós, \u{2068}{$name}\u{2069}!");
+
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let errors = Arc::clone(&reported);
+        i18n.set_on_error(move |error| {
+            errors
+                .lock()
+                .unwrap()
+                .push((error.language.id, error.kind.clone()));
+            "error".to_string()
+        });
+        assert_eq!(tr!("farewell"), "error");
+        let reported = reported.lock().unwrap();
+        assert_eq!(reported[0].0, "es");
+        assert!(matches!(reported[0].1, TranslationErrorKind::Format(_)));
+    });
+
+    // capturing closures are accepted as handlers
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    Owner::new().with(|| {
+        let reported = Arc::clone(&errors);
+        leptos_fluent! {
+            children: (),
+            locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_isolating",
+            default_language: "es",
+            #[cfg(debug_assertions)]
+            on_error: move |error| {
+                reported.lock().unwrap().push((
+                    error.text_id.to_string(),
+                    error.language.id,
+                    error.kind.clone(),
+                ));
+                "error".to_string()
+            },
+        };
+        assert_eq!(tr!("missing"), "error");
+        assert_eq!(tr!("greeting"), "error");
+    });
+    {
+        let errors = errors.lock().unwrap();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors[0],
+            (
+                "missing".to_string(),
+                "es",
+                TranslationErrorKind::MissingMessage
+            )
+        );
+        assert_eq!((errors[1].0.as_str(), errors[1].1), ("greeting", "es"));
+        assert!(matches!(errors[1].2, TranslationErrorKind::Format(_)));
+    }
+
+    // loaders of `static_loader!` passed with `translations` distinguish the
+    // errors too
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            translations: [TRANSLATIONS],
+            locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_isolating",
+            default_language: "en",
+        };
+        let kinds = Arc::new(Mutex::new(Vec::new()));
+        let reported = Arc::clone(&kinds);
+        expect_context::<I18n>().set_on_error(move |error| {
+            reported.lock().unwrap().push(error.kind.clone());
+            String::new()
+        });
+        tr!("missing");
+        tr!("greeting");
+        let kinds = kinds.lock().unwrap();
+        assert_eq!(kinds[0], TranslationErrorKind::MissingMessage);
+        assert!(matches!(kinds[1], TranslationErrorKind::Format(_)));
+    });
+}
@@ -0,0 +1,69 @@
+// Pseudolocales are generated from the translations of the default language.
+use leptos::prelude::*;
+use leptos_fluent::{I18n, Language, WritingDirection};
+use leptos_fluent_macros::leptos_fluent;
+
+static ES_XA: Language =
+    Language::new("es-XA", "Español", &WritingDirection::Ltr);
+
+fn main() {
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            locales: "../../../../examples/csr-minimal/locales",
+            default_language: "en",
+            pseudolocales: ["en-XA", "ar-XB"],
+        };
+        let i18n = expect_context::<I18n>();
+        let language =
+            |id| *i18n.languages.iter().find(|lang| lang.id == id).unwrap();
+
+        let en_xa = language("en-XA");
+        assert_eq!(en_xa.name, "English (Pseudo-Accents)");
+        assert_eq!(en_xa.dir.as_str(), "ltr");
+        let ar_xb = language("ar-XB");
+        assert_eq!(ar_xb.name, "Arabic (Pseudo-Bidi)");
+        assert_eq!(ar_xb.dir.as_str(), "rtl");
+
+        assert_eq!(i18n.tr("select-a-language"), "Select a language:");
+
+        i18n.language.set(en_xa);
+        assert_eq!(i18n.tr("select-a-language"), "Şḗḗŀḗḗƈŧ ȧȧ ŀȧȧƞɠŭŭȧȧɠḗḗ:");
+
+        i18n.language.set(ar_xb);
+        assert_eq!(
+            i18n.tr("select-a-language"),
+            "\u{202e}Sǝʅǝɔʇ ɐ ʅɐuƃnɐƃǝ:\u{202c}"
+        );
+
+        i18n.language.set(language("es"));
+        assert_eq!(i18n.tr("select-a-language"), "Selecciona un idioma:");
+
+        // only the exact `en-XA` and `ar-XB` identifiers are pseudolocales
+        i18n.add_resource(
+            "es-XA",
+            "select-a-language = Elige un idioma:".to_string(),
+        )
+        .unwrap();
+        i18n.language.set(&ES_XA);
+        assert_eq!(i18n.tr("select-a-language"), "Elige un idioma:");
+    });
+
+    // the resources of the pseudolocales are shared between contexts
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            locales: "../../../../examples/csr-minimal/locales",
+            default_language: "en",
+            pseudolocales: ["en-XA"],
+        };
+        let i18n = expect_context::<I18n>();
+        i18n.language.set(
+            i18n.languages
+                .iter()
+                .find(|lang| lang.id == "en-XA")
+                .unwrap(),
+        );
+        assert_eq!(i18n.tr("select-a-language"), "Şḗḗŀḗḗƈŧ ȧȧ ŀȧȧƞɠŭŭȧȧɠḗḗ:");
+    });
+}
@@ -0,0 +1,78 @@
+// Route slugs translated with `route-*` messages.
+use leptos::prelude::*;
+use leptos_fluent::components::LocalizedA;
+use leptos_fluent::routes::{canonical_segment, localize_path, slug, LocalizedSegment};
+use leptos_fluent::{leptos_fluent, localized_href, move_tr, I18n};
+
+#[component]
+fn I18nProvider(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_routes",
+        check_translations: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/route_slugs.rs",
+    }
+}
+
+#[component]
+pub fn Pricing() -> impl IntoView {
+    view! {
+        <h1>{move_tr!("pricing-title")}</h1>
+        <LocalizedA href="/pricing/plans">"Plans"</LocalizedA>
+    }
+}
+
+fn main() {
+    Owner::new().with(|| {
+        _ = view! {
+            <I18nProvider>
+                {
+                    use leptos_router::PossibleRouteMatch;
+
+                    let i18n = expect_context::<I18n>();
+
+                    // segments are known without declaring them in routes
+                    assert_eq!(canonical_segment(&i18n, "planes"), Some("plans"));
+                    // slugs don't include isolation marks
+                    assert_eq!(slug(&i18n, i18n.languages[1], "store"), "tienda-en-linea");
+                    assert_eq!(canonical_segment(&i18n, "tienda-en-linea"), Some("store"));
+
+                    let pricing = LocalizedSegment::new("pricing");
+
+                    assert_eq!(pricing.segment(), "pricing");
+                    assert_eq!(pricing.test("/pricing").unwrap().matched(), "/pricing");
+                    let matched = pricing.test("/precios/planes").unwrap();
+                    assert_eq!(matched.matched(), "/precios");
+                    assert_eq!(matched.remaining(), "/planes");
+                    assert!(pricing.test("/about").is_none());
+                    assert!(pricing.test("/").is_none());
+
+                    assert_eq!(canonical_segment(&i18n, "precios"), Some("pricing"));
+                    assert_eq!(canonical_segment(&i18n, "about"), None);
+                    assert_eq!(
+                        localize_path(&i18n, Some(i18n.languages[1]), "/pricing/plans?page=2"),
+                        "/precios/planes?page=2",
+                    );
+                    assert_eq!(localize_path(&i18n, None, "/precios/planes"), "/pricing/plans");
+
+                    // slugs are percent-decoded from paths and encoded in them
+                    assert_eq!(canonical_segment(&i18n, "categor%C3%ADas"), Some("categories"));
+                    assert_eq!(canonical_segment(&i18n, "categorías"), Some("categories"));
+                    assert!(LocalizedSegment::new("categories").test("/categor%C3%ADas").is_some());
+                    assert_eq!(
+                        localize_path(&i18n, Some(i18n.languages[1]), "/categories"),
+                        "/categor%C3%ADas",
+                    );
+                    assert_eq!(localize_path(&i18n, None, "/categor%C3%ADas"), "/categories");
+                    assert_eq!(localize_path(&i18n, None, "/caf%C3%A9"), "/caf%C3%A9");
+
+                    assert_eq!(localized_href("/es/precios"), "/en/pricing");
+                    i18n.language.set(i18n.languages[1]);
+                    assert_eq!(localized_href("/pricing"), "/es/precios");
+                    assert_eq!(localized_href("/en/pricing/plans#faq"), "/es/precios/planes#faq");
+                    assert_eq!(localized_href("/about"), "/es/about");
+                    assert_eq!(localized_href("/en/categories"), "/es/categor%C3%ADas");
+                }
+            </I18nProvider>
+        };
+    });
+}
@@ -0,0 +1,41 @@
+// Declarative translations with the `<Tr/>` component.
+use leptos::prelude::*;
+use leptos_fluent::__reexports::fluent_bundle::FluentValue;
+use leptos_fluent::components::{Tr, TrArg};
+use leptos_fluent::leptos_fluent;
+
+#[component]
+fn I18nProvider(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_widget",
+        check_translations: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/tr_component.rs",
+    }
+}
+
+#[component]
+fn App() -> impl IntoView {
+    let name = RwSignal::new("Jane".to_string());
+    view! {
+        <Tr id="widget-title" />
+        <Tr id="widget-greeting" args=vec![("name", name.into())] tag="p" attr:class="greeting" />
+    }
+}
+
+fn main() {
+    let name = RwSignal::new("Jane".to_string());
+    let arg = TrArg::from(name);
+    assert_eq!(arg.get(), FluentValue::from("Jane"));
+    name.set("John".to_string());
+    assert_eq!(arg.get(), FluentValue::from("John"));
+    assert_eq!(TrArg::from(3).get(), FluentValue::from(3));
+    assert_eq!(TrArg::new(move || name.get().len()).get(), FluentValue::from(4));
+
+    Owner::new().with(|| {
+        _ = view! {
+            <I18nProvider>
+                <App />
+            </I18nProvider>
+        };
+    });
+}
@@ -0,0 +1,28 @@
+// Message attributes can be translated with `message.attribute` identifiers
+// and are checked by `check_translations`.
+use leptos::prelude::*;
+use leptos_fluent::{move_tr, tr, I18n};
+use leptos_fluent_macros::leptos_fluent;
+
+fn main() {
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_attributes",
+            default_language: "en",
+            check_translations: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/tr_macros_attributes.rs",
+        };
+        assert_eq!(tr!("login-input"), "Username");
+        assert_eq!(
+            tr!("login-input.placeholder", { "name" => "username" }),
+            "Enter \u{2068}username\u{2069}"
+        );
+        let aria_label = move_tr!("login-input.aria-label");
+        assert_eq!(aria_label.get_untracked(), "Login");
+
+        let i18n = expect_context::<I18n>();
+        let spanish = i18n.languages.iter().find(|lang| lang.id == "es").unwrap();
+        i18n.language.set(spanish);
+        assert_eq!(aria_label.get_untracked(), "Acceder");
+    });
+}
@@ -0,0 +1,36 @@
+// Messages passed to `try_tr!` and `try_move_tr!` are only looked up in the
+// current language and are not required to be defined for all languages by
+// `check_translations`.
+use leptos::prelude::*;
+use leptos_fluent::{move_tr, try_move_tr, try_tr, I18n};
+use leptos_fluent_macros::leptos_fluent;
+
+fn main() {
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_optional",
+            default_language: "en",
+            check_translations: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/try_tr_macros.rs",
+        };
+        let i18n = expect_context::<I18n>();
+        let banner = try_move_tr!("marketing-banner");
+
+        assert_eq!(move_tr!("title").get_untracked(), "Store");
+        assert_eq!(try_tr!("marketing-banner"), Some("Summer sale!".to_string()));
+        assert_eq!(banner.get_untracked(), Some("Summer sale!".to_string()));
+        assert_eq!(
+            try_tr!(i18n, "discount", { "percent" => 20 }),
+            Some("\u{2068}20\u{2069}% off".to_string())
+        );
+        assert_eq!(i18n.try_tr("missing"), None);
+
+        // the default language is not used as fallback
+        let spanish = i18n.languages.iter().find(|lang| lang.id == "es").unwrap();
+        i18n.language.set(spanish);
+        assert_eq!(move_tr!("title").get_untracked(), "Tienda");
+        assert_eq!(try_tr!("marketing-banner"), None);
+        assert_eq!(banner.get_untracked(), None);
+        assert_eq!(try_tr!(i18n, "discount", { "percent" => 20 }), None);
+    });
+}
@@ -0,0 +1,60 @@
+// Built-in URL path strategy with the language as the first segment.
+use leptos::prelude::*;
+use leptos_fluent::components::LocalizedA;
+use leptos_fluent::{leptos_fluent, localized_href, I18n};
+
+#[component]
+fn I18nProvider(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../examples/csr-minimal/locales",
+    }
+}
+
+#[component]
+fn UrlPathI18nProvider(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "../../../../examples/csr-minimal/locales",
+        url_path: true,
+        initial_language_from_url_path: true,
+        set_language_to_url_path: true,
+        sync_hreflang_links: true,
+        hreflang_origin: "https://example.com",
+    }
+}
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <UrlPathI18nProvider>
+            <LocalizedA href="/about">"About"</LocalizedA>
+            <LocalizedA href="/" exact=true>"Home"</LocalizedA>
+        </UrlPathI18nProvider>
+    }
+}
+
+fn main() {
+    Owner::new().with(|| {
+        _ = view! {
+            <I18nProvider>
+                {
+                    let i18n = expect_context::<I18n>();
+                    assert_eq!(localized_href("/"), "/en");
+                    assert_eq!(localized_href("/about"), "/en/about");
+                    assert_eq!(localized_href("/es/about?page=2"), "/en/about?page=2");
+                    assert_eq!(localized_href("/ES"), "/en");
+                    assert_eq!(localized_href("/espresso"), "/en/espresso");
+                    assert_eq!(localized_href("/?page=2"), "/en?page=2");
+
+                    i18n.language.set(i18n.languages[1]);
+                    assert_eq!(localized_href("/en/about#team"), "/es/about#team");
+
+                    assert_eq!(localized_href("about"), "about");
+                    assert_eq!(localized_href("//cdn.example.com/a.js"), "//cdn.example.com/a.js");
+                    assert_eq!(localized_href("https://example.com"), "https://example.com");
+                }
+            </I18nProvider>
+        };
+    });
+}
@@ -0,0 +1,75 @@
+// Placeables are isolated or not globally and per macro call, also in the
+// messages of the loaders of `static_loader!` passed with `translations`.
+use leptos::prelude::*;
+use leptos_fluent::{move_tr, static_loader, tr, try_tr, I18n};
+use leptos_fluent_macros::leptos_fluent;
+
+static_loader! {
+    static TRANSLATIONS = {
+        locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_isolating",
+        fallback_language: "en",
+    };
+}
+
+fn main() {
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_isolating",
+            default_language: "en",
+            check_translations: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/use_isolating.rs",
+            use_isolating: false,
+        };
+        let i18n = expect_context::<I18n>();
+        assert!(!i18n.use_isolating());
+        assert_eq!(tr!("greeting", { "name" => "Jane" }), "Hello, Jane!");
+        // marks written in the translations are preserved
+        assert_eq!(tr!("quoted"), "\u{2068}Jane\u{2069}");
+        assert_eq!(
+            tr!(use_isolating = true, "greeting", { "name" => "Jane" }),
+            "Hello, \u{2068}Jane\u{2069}!"
+        );
+        assert_eq!(
+            try_tr!(use_isolating = true, i18n, "greeting", { "name" => "Jane" }),
+            Some("Hello, \u{2068}Jane\u{2069}!".to_string())
+        );
+        assert!(!i18n.use_isolating());
+
+        i18n.set_use_isolating(true);
+        assert_eq!(tr!("greeting", { "name" => "Jane" }), "Hello, \u{2068}Jane\u{2069}!");
+        assert_eq!(
+            move_tr!(use_isolating = false, "greeting", { "name" => "Jane" }).get_untracked(),
+            "Hello, Jane!"
+        );
+
+        // resources added at runtime too
+        i18n.add_resource("en", "farewell = Bye, { $name }!".to_string())
+            .unwrap();
+        let args = [("name".into(), "Jane".into())].into_iter().collect();
+        assert_eq!(i18n.tr_with_args("farewell", &args), "Bye, \u{2068}Jane\u{2069}!");
+        i18n.set_use_isolating(false);
+        assert_eq!(i18n.tr_with_args("farewell", &args), "Bye, Jane!");
+    });
+
+    Owner::new().with(|| {
+        let use_isolating = false;
+        leptos_fluent! {
+            children: (),
+            translations: [TRANSLATIONS],
+            locales: "../../../../leptos-fluent-macros/tests/ui/leptos_fluent/stable/pass/locales_isolating",
+            default_language: "es",
+            use_isolating,
+        };
+        assert_eq!(tr!("greeting", { "name" => "Juan" }), "¡Hola, Juan!");
+        assert_eq!(tr!("quoted"), "\u{2068}Juan\u{2069}");
+        assert_eq!(
+            tr!(use_isolating = true, "greeting", { "name" => "Juan" }),
+            "¡Hola, \u{2068}Juan\u{2069}!"
+        );
+        expect_context::<I18n>().set_use_isolating(true);
+        assert_eq!(
+            tr!("greeting", { "name" => "Juan" }),
+            "¡Hola, \u{2068}Juan\u{2069}!"
+        );
+    });
+}
@@ -0,0 +1,85 @@
+//! Benchmark of the translation of messages.
+//!
+//! Compares messages without arguments, served from the messages cache after
+//! the first translation, with the same messages resolved from the loaders
+//! on each call.
+//!
+//! ```sh
+//! cargo bench -p leptos-fluent --bench tr
+//! ```
+
+use fluent_templates::{langid, static_loader};
+use leptos::prelude::{Owner, RwSignal, Set, Signal};
+use leptos_fluent::{I18n, Language, WritingDirection};
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+static_loader! {
+    static TRANSLATIONS = {
+        locales: "../examples/csr-minimal/locales",
+        fallback_language: "en",
+    };
+}
+
+static EN: Language = Language::with_parsed_identifier(
+    "en",
+    langid!("en"),
+    "English",
+    &WritingDirection::Ltr,
+);
+
+static ES: Language = Language::with_parsed_identifier(
+    "es",
+    langid!("es"),
+    "Español",
+    &WritingDirection::Ltr,
+);
+
+static LANGUAGES: &[&Language] = &[&EN, &ES];
+
+const ITERATIONS: u32 = 100_000;
+
+/// Average time of a translation.
+fn bench(name: &str, mut f: impl FnMut()) -> Duration {
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed() / ITERATIONS;
+    println!("{name:<32} {elapsed:>10.2?}/iter");
+    elapsed
+}
+
+fn main() {
+    Owner::new().with(|| {
+        let i18n = I18n::new(
+            RwSignal::new(&ES),
+            LANGUAGES,
+            Signal::stored(vec![&TRANSLATIONS]),
+        );
+        let no_args = HashMap::new();
+
+        let uncached = bench("tr_with_args (no arguments)", || {
+            black_box(i18n.tr_with_args(black_box("select-a-language"), &no_args));
+        });
+        let cached = bench("tr (cached)", || {
+            black_box(i18n.tr(black_box("select-a-language")));
+        });
+        let mut english = false;
+        let switching = bench("tr (switching languages)", || {
+            english = !english;
+            i18n.language.set(if english { &EN } else { &ES });
+            black_box(i18n.tr(black_box("select-a-language")));
+        });
+
+        println!(
+            "\nCached translations are {:.1}x faster, {:.1}x when switching languages",
+            uncached.as_secs_f64() / cached.as_secs_f64(),
+            uncached.as_secs_f64() / switching.as_secs_f64(),
+        );
+    });
+}
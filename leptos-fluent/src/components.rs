@@ -0,0 +1,609 @@
+//! Components to internationalize applications.
+//!
+//! The language selectors render the options of all the languages of the
+//! application on the server and only update their state reactively, so
+//! their markup is the same on SSR and hydrate. Each option defines the
+//! `lang` and `dir` attributes of its language, and changes of language made
+//! through them are announced to screen readers by an ARIA live region.
+
+#[cfg(feature = "router")]
+use crate::localized_href;
+use crate::{expect_i18n_namespace, I18n, Language, LeptosFluentMeta};
+use fluent_bundle::FluentValue;
+use leptos::{
+    attr::custom::custom_attribute, context::Provider, either::Either, html,
+    prelude::*,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Styles to hide the ARIA live regions visually but not for screen readers.
+const VISUALLY_HIDDEN: &str = concat!(
+    "position:absolute;width:1px;height:1px;padding:0;margin:-1px;",
+    "overflow:hidden;clip:rect(0,0,0,0);white-space:nowrap;border:0",
+);
+
+/// Render a subtree in another language.
+///
+/// Provides to its children a context derived with [`I18n::scope`], so
+/// [`tr!`](crate::tr) and the rest of translation macros inside translate
+/// to `lang` while the language of the application is not changed. The
+/// language of the scope follows `lang` when it's a signal and can be
+/// changed from the children through the `language` field of the context.
+///
+/// When `tag` is defined, the children are wrapped in an element with that
+/// tag and the `lang` and `dir` attributes of the language of the scope.
+///
+/// ```rust,ignore
+/// use leptos::prelude::*;
+/// use leptos_fluent::{components::LanguageScope, move_tr, Language};
+///
+/// #[component]
+/// fn EmailPreview(recipient_language: &'static Language) -> impl IntoView {
+///     view! {
+///         <LanguageScope lang=recipient_language tag="article">
+///             <p>{move_tr!("email-greeting")}</p>
+///         </LanguageScope>
+///     }
+/// }
+/// ```
+#[component]
+pub fn LanguageScope(
+    /// Language of the subtree.
+    #[prop(into)]
+    lang: Signal<&'static Language>,
+    /// Tag of the element wrapping the subtree, if any.
+    #[prop(optional)]
+    tag: Option<&'static str>,
+    children: Children,
+) -> impl IntoView {
+    let i18n = expect_context::<I18n>().scope(lang.get_untracked());
+    Effect::new(move |_| {
+        let language = lang.get();
+        if i18n.language.get_untracked() != language {
+            i18n.language.set(language);
+        }
+    });
+
+    let children = view! { <Provider value=i18n>{children()}</Provider> };
+    match tag {
+        Some(tag) => Either::Left(
+            html::custom(tag)
+                .add_any_attr(custom_attribute("lang", move || {
+                    i18n.language.get().id
+                }))
+                .add_any_attr(custom_attribute("dir", move || {
+                    i18n.language.get().dir.as_str()
+                }))
+                .child(children),
+        ),
+        None => Either::Right(children),
+    }
+}
+
+/// Select to change the language of the application.
+///
+/// ```rust,ignore
+/// use leptos::prelude::*;
+/// use leptos_fluent::{components::LanguageSelect, move_tr};
+///
+/// view! { <LanguageSelect label=move_tr!("select-a-language") flags=true /> }
+/// ```
+#[component]
+pub fn LanguageSelect(
+    /// Accessible label of the select.
+    #[prop(optional, into)]
+    label: MaybeProp<String>,
+    /// Whether to show the flags of the languages before their names.
+    #[prop(optional)]
+    flags: bool,
+) -> impl IntoView {
+    let i18n = expect_context::<I18n>();
+    let announced = RwSignal::new(None);
+
+    let options = i18n
+        .languages
+        .iter()
+        .map(|lang| {
+            view! {
+                <option
+                    value=lang.id
+                    lang=lang.id
+                    dir=lang.dir.as_str()
+                    selected=move || i18n.language.get() == *lang
+                >
+                    {language_label(lang, flags)}
+                </option>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <select
+            aria-label=move || label.get()
+            prop:value=move || i18n.language.get().id
+            on:change=move |ev| {
+                let id = event_target_value(&ev);
+                if let Some(lang) = i18n.languages.iter().find(|lang| lang.id == id) {
+                    select_language(i18n, announced, lang);
+                }
+            }
+        >
+            {options}
+        </select>
+        {live_region(announced)}
+    }
+}
+
+/// Group of radio buttons to change the language of the application.
+///
+/// ```rust,ignore
+/// use leptos::prelude::*;
+/// use leptos_fluent::{components::LanguageRadioGroup, move_tr};
+///
+/// view! { <LanguageRadioGroup legend=move_tr!("select-a-language") /> }
+/// ```
+#[component]
+pub fn LanguageRadioGroup(
+    /// Legend of the group.
+    #[prop(optional, into)]
+    legend: MaybeProp<String>,
+    /// Name of the radio buttons. By default, `"language"`.
+    #[prop(optional)]
+    name: Option<&'static str>,
+    /// Whether to show the flags of the languages before their names.
+    #[prop(optional)]
+    flags: bool,
+) -> impl IntoView {
+    let i18n = expect_context::<I18n>();
+    let announced = RwSignal::new(None);
+    let name = name.unwrap_or("language");
+
+    let radios = i18n
+        .languages
+        .iter()
+        .map(|lang| {
+            let active = move || i18n.language.get() == *lang;
+            view! {
+                <label lang=lang.id dir=lang.dir.as_str()>
+                    <input
+                        type="radio"
+                        name=name
+                        value=lang.id
+                        checked=active
+                        prop:checked=active
+                        on:change=move |_| select_language(i18n, announced, lang)
+                    />
+                    {language_label_view(lang, flags)}
+                </label>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <fieldset>
+            {move || legend.get().map(|legend| view! { <legend>{legend}</legend> })}
+            {radios}
+        </fieldset>
+        {live_region(announced)}
+    }
+}
+
+/// Navigation with links to the application in each language.
+///
+/// By default, the links point to the current page with the URL parameter
+/// of the language, which is `lang` unless the meta context provided with
+/// the `provide_meta_context` parameter of `leptos_fluent!` defines another
+/// one. Pass `href` to build the links for other strategies.
+///
+/// ```rust,ignore
+/// use leptos::prelude::*;
+/// use leptos_fluent::components::LanguageLinks;
+///
+/// view! { <LanguageLinks href=|lang| format!("/{}/", lang.id) /> }
+/// ```
+#[component]
+pub fn LanguageLinks(
+    /// Accessible label of the navigation.
+    #[prop(optional, into)]
+    label: MaybeProp<String>,
+    /// Function to build the link to a language.
+    #[prop(optional)]
+    href: Option<fn(&'static Language) -> String>,
+    /// Whether to show the flags of the languages before their names.
+    #[prop(optional)]
+    flags: bool,
+) -> impl IntoView {
+    let i18n = expect_context::<I18n>();
+    let announced = RwSignal::new(None);
+    let url_param =
+        use_context::<LeptosFluentMeta>().map_or("lang", |meta| meta.url_param);
+
+    let links = i18n
+        .languages
+        .iter()
+        .map(|lang| {
+            let href = match href {
+                Some(href) => href(lang),
+                None => format!("?{}={}", url_param, lang.id),
+            };
+            view! {
+                <li>
+                    <a
+                        href=href
+                        hreflang=lang.id
+                        lang=lang.id
+                        dir=lang.dir.as_str()
+                        aria-current=move || {
+                            (i18n.language.get() == *lang).then_some("true")
+                        }
+                        on:click=move |_| select_language(i18n, announced, lang)
+                    >
+                        {language_label_view(lang, flags)}
+                    </a>
+                </li>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <nav aria-label=move || label.get()>
+            <ul>{links}</ul>
+        </nav>
+        {live_region(announced)}
+    }
+}
+
+/// Name of a language, optionally preceded by its flag.
+fn language_label(lang: &'static Language, flags: bool) -> String {
+    match lang.flag {
+        Some(flag) if flags => format!("{} {}", flag, lang.name),
+        _ => lang.name.to_string(),
+    }
+}
+
+/// Name of a language, optionally preceded by its flag hidden to screen
+/// readers.
+fn language_label_view(lang: &'static Language, flags: bool) -> impl IntoView {
+    let flag = lang.flag.filter(|_| flags).map(|flag| {
+        view! { <span aria-hidden="true">{flag} " "</span> }
+    });
+    view! { {flag} {lang.name} }
+}
+
+/// Set the language selected by the user to announce the change.
+fn select_language(
+    i18n: I18n,
+    announced: RwSignal<Option<&'static Language>>,
+    lang: &'static Language,
+) {
+    i18n.language.set(lang);
+    announced.set(Some(lang));
+}
+
+/// ARIA live region announcing the languages selected by the user.
+///
+/// It's empty until the user changes the language, so it's not announced
+/// when the page is loaded.
+fn live_region(
+    announced: RwSignal<Option<&'static Language>>,
+) -> impl IntoView {
+    view! {
+        <span
+            role="status"
+            aria-live="polite"
+            style=VISUALLY_HIDDEN
+            lang=move || announced.get().map(|lang| lang.id)
+        >
+            {move || announced.get().map(|lang| lang.name)}
+        </span>
+    }
+}
+
+/// Value of an argument of the [`<Tr/>`](Tr) component.
+///
+/// Created from strings, numbers and signals of them, so the translation is
+/// updated when the signals change.
+#[derive(Clone)]
+pub struct TrArg(Arc<dyn Fn() -> FluentValue<'static> + Send + Sync>);
+
+impl TrArg {
+    /// Argument computed by a function, tracking the signals read inside.
+    pub fn new<T>(value: impl Fn() -> T + Send + Sync + 'static) -> Self
+    where
+        T: Into<FluentValue<'static>>,
+    {
+        Self(Arc::new(move || value().into()))
+    }
+
+    /// Current value of the argument.
+    pub fn get(&self) -> FluentValue<'static> {
+        (self.0)()
+    }
+}
+
+impl core::fmt::Debug for TrArg {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("TrArg").field(&self.get()).finish()
+    }
+}
+
+impl From<&'static str> for TrArg {
+    fn from(value: &'static str) -> Self {
+        Self::new(move || value)
+    }
+}
+
+impl From<String> for TrArg {
+    fn from(value: String) -> Self {
+        Self::new(move || value.clone())
+    }
+}
+
+macro_rules! impl_from_number_for_tr_arg {
+    ($($number:ty),+) => {
+        $(
+            impl From<$number> for TrArg {
+                fn from(value: $number) -> Self {
+                    Self::new(move || value)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_number_for_tr_arg!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+macro_rules! impl_from_signal_for_tr_arg {
+    ($($signal:ident),+) => {
+        $(
+            impl<T> From<$signal<T>> for TrArg
+            where
+                T: Into<FluentValue<'static>> + Clone + Send + Sync + 'static,
+            {
+                fn from(value: $signal<T>) -> Self {
+                    Self::new(move || value.get())
+                }
+            }
+        )+
+    };
+}
+
+impl_from_signal_for_tr_arg!(Signal, ReadSignal, RwSignal, Memo);
+
+/// Render a reactive translation.
+///
+/// The component form of [`move_tr!`](crate::move_tr). The translation is
+/// wrapped in a `<span>` element, or in an element with the tag passed with
+/// `tag`, so attributes like `attr:class` can be applied to it.
+///
+/// ```rust,ignore
+/// use leptos::prelude::*;
+/// use leptos_fluent::components::Tr;
+///
+/// let name = RwSignal::new("Jane".to_string());
+/// view! {
+///     <Tr id="hello-world" />
+///     <Tr id="hello-args" args=vec![("name", name.into())] tag="p" attr:class="greeting" />
+/// }
+/// ```
+///
+/// The translations checker validates the identifiers and arguments of the
+/// components when the arguments are passed as a literal list of tuples.
+#[component]
+pub fn Tr(
+    /// Identifier of the message.
+    id: &'static str,
+    /// Arguments of the message.
+    #[prop(optional, into)]
+    args: Vec<(&'static str, TrArg)>,
+    /// Namespace of the context to translate with, defined with the
+    /// `namespace` parameter of `leptos_fluent!`.
+    #[prop(optional)]
+    ns: Option<&'static str>,
+    /// Tag of the element wrapping the translation. By default, `"span"`.
+    #[prop(optional)]
+    tag: Option<&'static str>,
+) -> impl IntoView {
+    let i18n = match ns {
+        Some(ns) => expect_i18n_namespace(ns),
+        None => expect_context::<I18n>(),
+    };
+    let translation = move || {
+        if args.is_empty() {
+            i18n.tr(id)
+        } else {
+            i18n.tr_with_args(
+                id,
+                &args
+                    .iter()
+                    .map(|(key, value)| ((*key).into(), value.get()))
+                    .collect::<HashMap<_, _>>(),
+            )
+        }
+    };
+
+    html::custom(tag.unwrap_or("span")).child(translation)
+}
+
+/// Alternate links to the page in each language for search engines.
+///
+/// Renders a `<link rel="alternate" hreflang="...">` element in the
+/// `<head>` of the document through `leptos_meta` for each language of the
+/// application, and another one with `hreflang="x-default"` to the page
+/// without language. Rendered by `leptos_fluent!` with the
+/// `sync_hreflang_links` parameter and the origin of `hreflang_origin`.
+///
+/// The links point to the current page with the URL parameter of the
+/// language or, with `url_path`, with the language as the first segment of
+/// the path. Search engines require absolute URLs, so pass the `origin` of
+/// the application:
+///
+/// ```rust,ignore
+/// use leptos::prelude::*;
+/// use leptos_fluent::components::HreflangLinks;
+///
+/// view! { <HreflangLinks origin="https://example.com" /> }
+/// ```
+#[component]
+pub fn HreflangLinks(
+    /// Origin prepended to the links, like `https://example.com`.
+    #[prop(optional, into)]
+    origin: Option<String>,
+    /// Path and query of the current page. By default, those of the request
+    /// on the server and of the location of the browser on the client.
+    #[prop(optional, into)]
+    path: MaybeProp<String>,
+    /// URL parameter of the language. By default, `"lang"`.
+    #[prop(optional)]
+    url_param: Option<&'static str>,
+    /// Whether the language is the first segment of the URL path instead of
+    /// a URL parameter.
+    #[prop(optional)]
+    url_path: bool,
+) -> impl IntoView {
+    use leptos_meta::{provide_meta_context, Link};
+
+    if use_context::<leptos_meta::MetaContext>().is_none() {
+        provide_meta_context();
+    }
+    let i18n = expect_context::<I18n>();
+    let origin = origin.unwrap_or_default();
+    let url_param = url_param.unwrap_or("lang");
+    let current_path = current_path();
+    move || {
+        let path = path.get().unwrap_or_else(&current_path);
+        let href = |language: Option<&'static Language>| {
+            let href = if url_path {
+                crate::url::path::localize_href(&i18n, language, &path)
+            } else {
+                alternate_url_param(&path, language, url_param)
+            };
+            format!("{origin}{href}")
+        };
+
+        let links = i18n
+            .languages
+            .iter()
+            .map(|lang| {
+                view! { <Link rel="alternate" hreflang=lang.id href=href(Some(lang)) /> }
+            })
+            .collect::<Vec<_>>();
+
+        view! {
+            {links}
+            <Link rel="alternate" hreflang="x-default" href=href(None) />
+        }
+    }
+}
+
+/// Link of `leptos_router` to a path of the application in the current
+/// language.
+///
+/// Renders the [`A`](leptos_router::components::A) component of
+/// `leptos_router` with its `href` localized by [`localized_href`], so
+/// navigating with the URL path strategy (`url_path: true`) preserves the
+/// language of the user. It must be rendered inside a `<Router/>`.
+///
+/// ```rust,ignore
+/// use leptos::prelude::*;
+/// use leptos_fluent::{components::LocalizedA, move_tr};
+///
+/// view! { <LocalizedA href="/about">{move_tr!("about")}</LocalizedA> }
+/// ```
+#[cfg(feature = "router")]
+#[component]
+pub fn LocalizedA(
+    /// Absolute path of the link without language, like `/about`.
+    #[prop(into)]
+    href: Signal<String>,
+    /// Whether the link is only marked as active when the location matches
+    /// exactly.
+    #[prop(optional)]
+    exact: bool,
+    /// Whether `aria-current` is only set for a `href` with a trailing slash
+    /// when the location also has it.
+    #[prop(optional)]
+    strict_trailing_slash: bool,
+    /// Whether the router scrolls to the top of the window after navigating.
+    #[prop(default = true)]
+    scroll: bool,
+    children: Children,
+) -> impl IntoView {
+    use leptos_router::components::A;
+
+    view! {
+        <A
+            href=move || localized_href(&href.get())
+            exact=exact
+            strict_trailing_slash=strict_trailing_slash
+            scroll=scroll
+        >
+            {children()}
+        </A>
+    }
+}
+
+/// Getter of the path and query of the current page.
+///
+/// Reads the request on the server and the location of the browser on the
+/// client, which is tracked after the navigations of `leptos_router` with
+/// the `router` feature.
+fn current_path() -> impl Fn() -> String {
+    #[cfg(not(feature = "ssr"))]
+    {
+        #[cfg(feature = "router")]
+        let router_url = use_context::<leptos_router::location::BrowserUrl>();
+
+        move || {
+            #[cfg(feature = "router")]
+            if let Some(ref router_url) = router_url {
+                use leptos_router::location::LocationProvider;
+
+                return router_url.as_url().with(|url| match url.search() {
+                    "" => url.path().to_string(),
+                    search => format!("{}?{}", url.path(), search),
+                });
+            }
+
+            let location = leptos::prelude::window().location();
+            format!(
+                "{}{}",
+                location.pathname().unwrap_or_default(),
+                location.search().unwrap_or_default()
+            )
+        }
+    }
+
+    #[cfg(feature = "ssr")]
+    {
+        let path = crate::server::request_path_and_query()
+            .unwrap_or_else(|| "/".to_string());
+        move || path.clone()
+    }
+}
+
+/// Path with the URL parameter of a language, or without it for `None`.
+fn alternate_url_param(
+    path: &str,
+    language: Option<&Language>,
+    url_param: &str,
+) -> String {
+    let (pathname, query) = path.split_once('?').unwrap_or((path, ""));
+    let mut params = query
+        .split('&')
+        .filter(|param| {
+            !param.is_empty() && param.split('=').next() != Some(url_param)
+        })
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    if let Some(language) = language {
+        params.push(format!("{}={}", url_param, language.id));
+    }
+    match params.is_empty() {
+        true => pathname.to_string(),
+        false => format!("{}?{}", pathname, params.join("&")),
+    }
+}
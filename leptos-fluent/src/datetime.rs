@@ -0,0 +1,757 @@
+//! Locale-aware formatting of dates and relative times.
+//!
+//! Enabled with the `datetime` feature. The bundles built by
+//! [`leptos_fluent!`] and [`static_loader!`] and the resources added with
+//! [`I18n::add_resource`] get the `DATETIME()` and `RELATIVETIME()`
+//! functions, which format using the data of the [CLDR], with [ICU4X] on the
+//! server or desktop and [`Intl.DateTimeFormat`] and
+//! [`Intl.RelativeTimeFormat`] in the browser.
+//!
+//! ```ftl
+//! updated = Updated on { DATETIME($date, dateStyle: "long", timeStyle: "short") }
+//! meeting = Meeting at { DATETIME($date, timeStyle: "short", hourCycle: "h12", timeZone: "America/New_York") }
+//! due = Due { RELATIVETIME($days, unit: "day", numeric: "auto") }
+//! ```
+//!
+//! `DATETIME()` accepts [`FluentDateTime`] values or numbers of milliseconds
+//! since the Unix epoch, with the options:
+//!
+//! - `dateStyle` and `timeStyle`: `full`, `long`, `medium` or `short`.
+//! - `hourCycle`: `h11`, `h12`, `h23` or `h24`, and `hour12`: `true` or
+//!   `false`.
+//! - `timeZone`: an IANA time zone name, like `Europe/Madrid`. Dates are
+//!   formatted in UTC on the server and in the local time zone on the
+//!   browser by default.
+//!
+//! `RELATIVETIME()` accepts numbers with the options `unit` (`second`,
+//! `minute`, `hour`, `day`, `week`, `month`, `quarter` or `year`),
+//! `numeric` (`always` or `auto`) and `style` (`long`, `short` or `narrow`).
+//!
+//! Dates and times of the [`chrono`] and [`time`] crates can be converted to
+//! [`FluentDateTime`] enabling the `chrono` and `time` features:
+//!
+//! ```rust,ignore
+//! tr!("updated", { "date" => FluentDateTime::from(chrono::Utc::now()) })
+//! ```
+//!
+//! Loaders built by `fluent_templates::static_loader!` format their
+//! messages with their own bundles, so call [`add_datetime_functions`]
+//! from their `customise` closure when passing them to the `translations`
+//! parameter of [`leptos_fluent!`].
+//!
+//! [`leptos_fluent!`]: crate::leptos_fluent
+//! [`static_loader!`]: crate::static_loader
+//! [`I18n::add_resource`]: crate::I18n::add_resource
+//! [CLDR]: https://cldr.unicode.org
+//! [ICU4X]: https://icu4x.unicode.org
+//! [`Intl.DateTimeFormat`]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat
+//! [`Intl.RelativeTimeFormat`]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat
+//! [`chrono`]: https://docs.rs/chrono
+//! [`time`]: https://docs.rs/time
+
+use fluent_bundle::{
+    bundle::FluentBundle, memoizer::MemoizerKind, types::FluentType,
+    FluentArgs, FluentValue,
+};
+use fluent_templates::LanguageIdentifier;
+use intl_memoizer::Memoizable;
+use std::borrow::Cow;
+
+/// Install the `DATETIME()` and `RELATIVETIME()` functions into a Fluent
+/// bundle.
+///
+/// ```rust,ignore
+/// static_loader! {
+///     static TRANSLATIONS = {
+///         locales: "./locales",
+///         fallback_language: "en",
+///         customise: |bundle| leptos_fluent::datetime::add_datetime_functions(bundle),
+///     };
+/// }
+/// ```
+pub fn add_datetime_functions<R, M: MemoizerKind>(
+    bundle: &mut FluentBundle<R, M>,
+) {
+    // functions already installed in the bundle are preserved
+    _ = bundle.add_function("DATETIME", datetime);
+    _ = bundle.add_function("RELATIVETIME", relative_time);
+}
+
+fn datetime<'a>(
+    positional: &[FluentValue<'a>],
+    named: &FluentArgs,
+) -> FluentValue<'a> {
+    let mut datetime = match positional.first() {
+        Some(FluentValue::Custom(custom)) => {
+            match (**custom).as_any().downcast_ref::<FluentDateTime>() {
+                Some(datetime) => datetime.clone(),
+                None => return FluentValue::Error,
+            }
+        }
+        Some(FluentValue::Number(number)) => {
+            FluentDateTime::from_epoch_milliseconds(number.value as i64)
+        }
+        _ => return FluentValue::Error,
+    };
+    datetime.options.merge(named);
+    datetime.into()
+}
+
+fn relative_time<'a>(
+    positional: &[FluentValue<'a>],
+    named: &FluentArgs,
+) -> FluentValue<'a> {
+    let value = match positional.first() {
+        Some(FluentValue::Number(number)) => number.value,
+        _ => return FluentValue::Error,
+    };
+    let unit = match named.get("unit") {
+        Some(FluentValue::String(unit)) => {
+            match RelativeTimeUnit::try_from(unit.as_ref()) {
+                Ok(unit) => unit,
+                Err(()) => return FluentValue::Error,
+            }
+        }
+        _ => return FluentValue::Error,
+    };
+    let numeric_auto = matches!(
+        named.get("numeric"),
+        Some(FluentValue::String(numeric)) if numeric == "auto"
+    );
+    let style = match named.get("style") {
+        Some(FluentValue::String(style)) => match style.as_ref() {
+            "short" => Width::Short,
+            "narrow" => Width::Narrow,
+            _ => Width::Long,
+        },
+        _ => Width::Long,
+    };
+    FluentValue::Custom(Box::new(RelativeTime {
+        value,
+        args: RelativeTimeFormatterArgs {
+            unit,
+            style,
+            numeric_auto,
+        },
+    }))
+}
+
+/// A date and time that can be passed as argument to translations.
+///
+/// Formatted with the `medium` date style when not passed to `DATETIME()`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FluentDateTime {
+    epoch_milliseconds: i64,
+    options: DateTimeOptions,
+}
+
+impl FluentDateTime {
+    /// Create a date and time from the milliseconds since the Unix epoch.
+    pub fn from_epoch_milliseconds(epoch_milliseconds: i64) -> Self {
+        Self {
+            epoch_milliseconds,
+            options: DateTimeOptions::default(),
+        }
+    }
+
+    /// Milliseconds since the Unix epoch.
+    pub fn epoch_milliseconds(&self) -> i64 {
+        self.epoch_milliseconds
+    }
+
+    /// Dates and times without time zone are formatted in UTC, so they're
+    /// not shifted to the local time zone of the browser.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    fn from_utc_epoch_milliseconds(epoch_milliseconds: i64) -> Self {
+        let mut datetime = Self::from_epoch_milliseconds(epoch_milliseconds);
+        datetime.options.time_zone = Some("UTC".to_string());
+        datetime
+    }
+
+    fn format<M: MemoizerKind>(&self, intls: &M) -> Cow<'static, str> {
+        let mut args = self.options.formatter_args();
+        if args.date_style.is_none() && args.time_style.is_none() {
+            args.date_style = Some(Width::Medium);
+        }
+        intls
+            .with_try_get_threadsafe::<DateTimeFormatter, _, _>(
+                args,
+                |formatter| {
+                    formatter.format(
+                        self.epoch_milliseconds,
+                        self.options.time_zone.as_deref(),
+                    )
+                },
+            )
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| self.epoch_milliseconds.to_string())
+            .into()
+    }
+}
+
+impl From<FluentDateTime> for FluentValue<'_> {
+    fn from(datetime: FluentDateTime) -> Self {
+        FluentValue::Custom(Box::new(datetime))
+    }
+}
+
+impl FluentType for FluentDateTime {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(
+        &self,
+        intls: &intl_memoizer::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        self.format(intls)
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        self.format(intls)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for FluentDateTime {
+    fn from(datetime: chrono::DateTime<Tz>) -> Self {
+        Self::from_epoch_milliseconds(datetime.timestamp_millis())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDateTime> for FluentDateTime {
+    fn from(datetime: chrono::NaiveDateTime) -> Self {
+        Self::from_utc_epoch_milliseconds(datetime.and_utc().timestamp_millis())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for FluentDateTime {
+    fn from(date: chrono::NaiveDate) -> Self {
+        date.and_time(chrono::NaiveTime::MIN).into()
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for FluentDateTime {
+    fn from(datetime: time::OffsetDateTime) -> Self {
+        Self::from_epoch_milliseconds(
+            (datetime.unix_timestamp_nanos() / 1_000_000) as i64,
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::PrimitiveDateTime> for FluentDateTime {
+    fn from(datetime: time::PrimitiveDateTime) -> Self {
+        Self::from_utc_epoch_milliseconds(
+            (datetime.assume_utc().unix_timestamp_nanos() / 1_000_000) as i64,
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Date> for FluentDateTime {
+    fn from(date: time::Date) -> Self {
+        date.midnight().into()
+    }
+}
+
+/// Width of the styles of dates, times and relative times.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Width {
+    Full,
+    Long,
+    Medium,
+    Short,
+    Narrow,
+}
+
+impl Width {
+    #[cfg(target_arch = "wasm32")]
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Long => "long",
+            Self::Medium => "medium",
+            Self::Short => "short",
+            Self::Narrow => "narrow",
+        }
+    }
+}
+
+/// Style of dates and times.
+fn datetime_style(input: &str) -> Option<Width> {
+    match input {
+        "full" => Some(Width::Full),
+        "long" => Some(Width::Long),
+        "medium" => Some(Width::Medium),
+        "short" => Some(Width::Short),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum HourCycle {
+    H11,
+    H12,
+    H23,
+    H24,
+    Clock12,
+    Clock24,
+}
+
+impl HourCycle {
+    #[cfg(target_arch = "wasm32")]
+    fn as_str(&self) -> Option<&'static str> {
+        match self {
+            Self::H11 => Some("h11"),
+            Self::H12 => Some("h12"),
+            Self::H23 => Some("h23"),
+            Self::H24 => Some("h24"),
+            Self::Clock12 | Self::Clock24 => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct DateTimeOptions {
+    date_style: Option<Width>,
+    time_style: Option<Width>,
+    hour_cycle: Option<HourCycle>,
+    time_zone: Option<String>,
+}
+
+impl DateTimeOptions {
+    fn merge(&mut self, args: &FluentArgs) {
+        for (key, value) in args.iter() {
+            let FluentValue::String(value) = value else {
+                continue;
+            };
+            match key {
+                "dateStyle" => self.date_style = datetime_style(value),
+                "timeStyle" => self.time_style = datetime_style(value),
+                "hourCycle" => {
+                    self.hour_cycle = match value.as_ref() {
+                        "h11" => Some(HourCycle::H11),
+                        "h12" => Some(HourCycle::H12),
+                        "h23" => Some(HourCycle::H23),
+                        "h24" => Some(HourCycle::H24),
+                        _ => None,
+                    }
+                }
+                "hour12" => {
+                    self.hour_cycle = Some(match value == "false" {
+                        true => HourCycle::Clock24,
+                        false => HourCycle::Clock12,
+                    })
+                }
+                "timeZone" => self.time_zone = Some(value.to_string()),
+                _ => {}
+            }
+        }
+    }
+
+    fn formatter_args(&self) -> DateTimeFormatterArgs {
+        DateTimeFormatterArgs {
+            date_style: self.date_style,
+            time_style: self.time_style,
+            hour_cycle: self.hour_cycle,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct DateTimeFormatterArgs {
+    date_style: Option<Width>,
+    time_style: Option<Width>,
+    hour_cycle: Option<HourCycle>,
+}
+
+/// Date and time formatter memoized by language and options in the bundles.
+struct DateTimeFormatter {
+    #[cfg(not(target_arch = "wasm32"))]
+    formatter: icu_datetime::DateTimeFormatter<
+        icu_datetime::fieldsets::enums::CompositeDateTimeFieldSet,
+    >,
+    /// `Intl.DateTimeFormat` is not thread safe, so it's built on formatting.
+    #[cfg(target_arch = "wasm32")]
+    args: DateTimeFormatterArgs,
+    #[cfg(target_arch = "wasm32")]
+    locale: String,
+}
+
+impl Memoizable for DateTimeFormatter {
+    type Args = DateTimeFormatterArgs;
+    type Error = ();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn construct(
+        lang: LanguageIdentifier,
+        args: Self::Args,
+    ) -> Result<Self, Self::Error> {
+        use icu_datetime::{
+            fieldsets::builder::{DateFields, FieldSetBuilder},
+            options::{Length, TimePrecision},
+            DateTimeFormatterPreferences,
+        };
+        use icu_locale_core::preferences::extensions::unicode::keywords;
+
+        let locale = lang
+            .to_string()
+            .parse::<icu_locale_core::Locale>()
+            .map_err(|_| ())?;
+
+        let mut builder = FieldSetBuilder::new();
+        // ICU4X uses the same length for dates and times
+        builder.length = Some(match args.date_style.or(args.time_style) {
+            Some(Width::Full | Width::Long) => Length::Long,
+            Some(Width::Short | Width::Narrow) => Length::Short,
+            Some(Width::Medium) | None => Length::Medium,
+        });
+        builder.date_fields = args.date_style.map(|style| match style {
+            Width::Full => DateFields::YMDE,
+            _ => DateFields::YMD,
+        });
+        builder.time_precision = args.time_style.map(|style| match style {
+            Width::Short | Width::Narrow => TimePrecision::Minute,
+            _ => TimePrecision::Second,
+        });
+        let field_set = builder.build_composite_datetime().map_err(|_| ())?;
+
+        let mut prefs = DateTimeFormatterPreferences::from(&locale);
+        if let Some(hour_cycle) = args.hour_cycle {
+            prefs.hour_cycle = Some(match hour_cycle {
+                HourCycle::H11 => keywords::HourCycle::H11,
+                HourCycle::H12 => keywords::HourCycle::H12,
+                HourCycle::H23 | HourCycle::H24 => keywords::HourCycle::H23,
+                HourCycle::Clock12 => keywords::HourCycle::Clock12,
+                HourCycle::Clock24 => keywords::HourCycle::Clock24,
+            });
+        }
+
+        Ok(Self {
+            formatter: icu_datetime::DateTimeFormatter::try_new(
+                prefs, field_set,
+            )
+            .map_err(|_| ())?,
+        })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn construct(
+        lang: LanguageIdentifier,
+        args: Self::Args,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            args,
+            locale: lang.to_string(),
+        })
+    }
+}
+
+impl DateTimeFormatter {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn format(
+        &self,
+        epoch_milliseconds: i64,
+        time_zone: Option<&str>,
+    ) -> Option<String> {
+        let timestamp =
+            jiff::Timestamp::from_millisecond(epoch_milliseconds).ok()?;
+        let datetime = timestamp.in_tz(time_zone.unwrap_or("UTC")).ok()?;
+        let datetime = datetime.datetime();
+        let input = icu_time::DateTime {
+            date: icu_calendar::Date::try_new_iso(
+                datetime.year() as i32,
+                datetime.month() as u8,
+                datetime.day() as u8,
+            )
+            .ok()?,
+            time: icu_time::Time::try_new(
+                datetime.hour() as u8,
+                datetime.minute() as u8,
+                datetime.second() as u8,
+                datetime.subsec_nanosecond() as u32,
+            )
+            .ok()?,
+        };
+        Some(self.formatter.format(&input).to_string())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn format(
+        &self,
+        epoch_milliseconds: i64,
+        time_zone: Option<&str>,
+    ) -> Option<String> {
+        use crate::intl::set_option;
+        use js_sys::{Date, Intl, Object};
+        use wasm_bindgen::JsValue;
+
+        let options = Object::new();
+        if let Some(date_style) = self.args.date_style {
+            set_option(&options, "dateStyle", date_style.as_str());
+        }
+        if let Some(time_style) = self.args.time_style {
+            set_option(&options, "timeStyle", time_style.as_str());
+        }
+        match self.args.hour_cycle {
+            Some(HourCycle::Clock12) => set_option(&options, "hour12", true),
+            Some(HourCycle::Clock24) => set_option(&options, "hour12", false),
+            Some(hour_cycle) => {
+                set_option(&options, "hourCycle", hour_cycle.as_str())
+            }
+            None => {}
+        }
+        if let Some(time_zone) = time_zone {
+            set_option(&options, "timeZone", time_zone);
+        }
+
+        let formatter = crate::intl::construct::<Intl::DateTimeFormat>(
+            "DateTimeFormat",
+            &self.locale,
+            &options,
+        )?;
+        let date = Date::new(&JsValue::from_f64(epoch_milliseconds as f64));
+        formatter
+            .format()
+            .call1(&JsValue::NULL, &date)
+            .ok()?
+            .as_string()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum RelativeTimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl TryFrom<&str> for RelativeTimeUnit {
+    type Error = ();
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        // plural forms are accepted like in `Intl.RelativeTimeFormat`
+        match input.strip_suffix('s').unwrap_or(input) {
+            "second" => Ok(Self::Second),
+            "minute" => Ok(Self::Minute),
+            "hour" => Ok(Self::Hour),
+            "day" => Ok(Self::Day),
+            "week" => Ok(Self::Week),
+            "month" => Ok(Self::Month),
+            "quarter" => Ok(Self::Quarter),
+            "year" => Ok(Self::Year),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct RelativeTimeFormatterArgs {
+    unit: RelativeTimeUnit,
+    style: Width,
+    numeric_auto: bool,
+}
+
+/// Value returned by `RELATIVETIME()`.
+#[derive(Clone, Debug, PartialEq)]
+struct RelativeTime {
+    value: f64,
+    args: RelativeTimeFormatterArgs,
+}
+
+impl RelativeTime {
+    fn format<M: MemoizerKind>(&self, intls: &M) -> Cow<'static, str> {
+        intls
+            .with_try_get_threadsafe::<RelativeTimeFormatter, _, _>(
+                self.args.clone(),
+                |formatter| formatter.format(self.value),
+            )
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| self.value.to_string())
+            .into()
+    }
+}
+
+impl FluentType for RelativeTime {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(
+        &self,
+        intls: &intl_memoizer::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        self.format(intls)
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        self.format(intls)
+    }
+}
+
+/// Relative time formatter memoized by language and options in the bundles.
+struct RelativeTimeFormatter {
+    #[cfg(not(target_arch = "wasm32"))]
+    formatter: icu_experimental::relativetime::RelativeTimeFormatter,
+    /// `Intl.RelativeTimeFormat` is not thread safe, so it's built on
+    /// formatting.
+    #[cfg(target_arch = "wasm32")]
+    args: RelativeTimeFormatterArgs,
+    #[cfg(target_arch = "wasm32")]
+    locale: String,
+}
+
+impl Memoizable for RelativeTimeFormatter {
+    type Args = RelativeTimeFormatterArgs;
+    type Error = ();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn construct(
+        lang: LanguageIdentifier,
+        args: Self::Args,
+    ) -> Result<Self, Self::Error> {
+        use icu_experimental::relativetime::{
+            options::{Numeric, RelativeTimeFormatterOptions},
+            RelativeTimeFormatter as Formatter,
+        };
+        use RelativeTimeUnit::*;
+
+        let locale = lang
+            .to_string()
+            .parse::<icu_locale_core::Locale>()
+            .map_err(|_| ())?;
+        let prefs = (&locale).into();
+        let mut options = RelativeTimeFormatterOptions::default();
+        if args.numeric_auto {
+            options.numeric = Numeric::Auto;
+        }
+
+        let formatter = match (args.style, args.unit) {
+            (Width::Short, Second) => {
+                Formatter::try_new_short_second(prefs, options)
+            }
+            (Width::Short, Minute) => {
+                Formatter::try_new_short_minute(prefs, options)
+            }
+            (Width::Short, Hour) => {
+                Formatter::try_new_short_hour(prefs, options)
+            }
+            (Width::Short, Day) => Formatter::try_new_short_day(prefs, options),
+            (Width::Short, Week) => {
+                Formatter::try_new_short_week(prefs, options)
+            }
+            (Width::Short, Month) => {
+                Formatter::try_new_short_month(prefs, options)
+            }
+            (Width::Short, Quarter) => {
+                Formatter::try_new_short_quarter(prefs, options)
+            }
+            (Width::Short, Year) => {
+                Formatter::try_new_short_year(prefs, options)
+            }
+            (Width::Narrow, Second) => {
+                Formatter::try_new_narrow_second(prefs, options)
+            }
+            (Width::Narrow, Minute) => {
+                Formatter::try_new_narrow_minute(prefs, options)
+            }
+            (Width::Narrow, Hour) => {
+                Formatter::try_new_narrow_hour(prefs, options)
+            }
+            (Width::Narrow, Day) => {
+                Formatter::try_new_narrow_day(prefs, options)
+            }
+            (Width::Narrow, Week) => {
+                Formatter::try_new_narrow_week(prefs, options)
+            }
+            (Width::Narrow, Month) => {
+                Formatter::try_new_narrow_month(prefs, options)
+            }
+            (Width::Narrow, Quarter) => {
+                Formatter::try_new_narrow_quarter(prefs, options)
+            }
+            (Width::Narrow, Year) => {
+                Formatter::try_new_narrow_year(prefs, options)
+            }
+            (_, Second) => Formatter::try_new_long_second(prefs, options),
+            (_, Minute) => Formatter::try_new_long_minute(prefs, options),
+            (_, Hour) => Formatter::try_new_long_hour(prefs, options),
+            (_, Day) => Formatter::try_new_long_day(prefs, options),
+            (_, Week) => Formatter::try_new_long_week(prefs, options),
+            (_, Month) => Formatter::try_new_long_month(prefs, options),
+            (_, Quarter) => Formatter::try_new_long_quarter(prefs, options),
+            (_, Year) => Formatter::try_new_long_year(prefs, options),
+        }
+        .map_err(|_| ())?;
+
+        Ok(Self { formatter })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn construct(
+        lang: LanguageIdentifier,
+        args: Self::Args,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            args,
+            locale: lang.to_string(),
+        })
+    }
+}
+
+impl RelativeTimeFormatter {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn format(&self, value: f64) -> Option<String> {
+        let value = fixed_decimal::Decimal::try_from_f64(
+            value,
+            fixed_decimal::FloatPrecision::RoundTrip,
+        )
+        .ok()?;
+        Some(self.formatter.format(value).to_string())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn format(&self, value: f64) -> Option<String> {
+        use crate::intl::set_option;
+        use js_sys::{Intl, Object};
+
+        let options = Object::new();
+        set_option(&options, "style", self.args.style.as_str());
+        if self.args.numeric_auto {
+            set_option(&options, "numeric", "auto");
+        }
+        let formatter = crate::intl::construct::<Intl::RelativeTimeFormat>(
+            "RelativeTimeFormat",
+            &self.locale,
+            &options,
+        )?;
+        let unit = match self.args.unit {
+            RelativeTimeUnit::Second => "second",
+            RelativeTimeUnit::Minute => "minute",
+            RelativeTimeUnit::Hour => "hour",
+            RelativeTimeUnit::Day => "day",
+            RelativeTimeUnit::Week => "week",
+            RelativeTimeUnit::Month => "month",
+            RelativeTimeUnit::Quarter => "quarter",
+            RelativeTimeUnit::Year => "year",
+        };
+        Some(formatter.format(value, unit).into())
+    }
+}
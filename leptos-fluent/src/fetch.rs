@@ -0,0 +1,43 @@
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+pub async fn text(url: &str) -> Option<String> {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    #[cfg(feature = "tracing")]
+    tracing::trace!("Fetching \"{}\" from browser", url);
+
+    let response =
+        match JsFuture::from(leptos::prelude::window().fetch_with_str(url))
+            .await
+        {
+            Ok(response) => response,
+            Err(_error) => {
+                #[cfg(feature = "tracing")]
+                tracing::error!("Failed to fetch \"{}\": {:?}", url, _error);
+                return None;
+            }
+        };
+    let response = response.dyn_into::<web_sys::Response>().ok()?;
+    if !response.ok() {
+        #[cfg(feature = "tracing")]
+        tracing::error!(
+            "Failed to fetch \"{}\": HTTP status {}",
+            url,
+            response.status()
+        );
+        return None;
+    }
+
+    match JsFuture::from(response.text().ok()?).await {
+        Ok(text) => text.as_string(),
+        Err(_error) => {
+            #[cfg(feature = "tracing")]
+            tracing::error!(
+                "Failed to read response body of \"{}\": {:?}",
+                url,
+                _error
+            );
+            None
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Custom Fluent functions.
+//!
+//! The functions passed to the `functions` parameter of [`leptos_fluent!`]
+//! are installed, along with the functions of the enabled features like
+//! `NUMBER()`, in the bundles built by [`leptos_fluent!`], in the bundles
+//! built from the loaders of [`static_loader!`] passed with its
+//! `translations` parameter and in the resources added with
+//! [`I18n::add_resource`] to its context.
+//!
+//! ```rust,ignore
+//! use fluent_templates::fluent_bundle::{FluentArgs, FluentValue};
+//!
+//! fn shout<'a>(
+//!     positional: &[FluentValue<'a>],
+//!     _named: &FluentArgs,
+//! ) -> FluentValue<'a> {
+//!     match positional.first() {
+//!         Some(FluentValue::String(s)) => s.to_uppercase().into(),
+//!         _ => FluentValue::Error,
+//!     }
+//! }
+//!
+//! leptos_fluent! {
+//!     // ...
+//!     functions: {
+//!         "SHOUT" => shout,
+//!     },
+//! };
+//! ```
+//!
+//! Loaders built by `fluent_templates::static_loader!` format their
+//! messages with their own bundles, so install the functions calling
+//! [`add_functions_with`] from their `customise` closure.
+//!
+//! [`leptos_fluent!`]: crate::leptos_fluent
+//! [`static_loader!`]: crate::static_loader
+//! [`I18n::add_resource`]: crate::I18n::add_resource
+
+use fluent_bundle::{
+    bundle::FluentBundle, memoizer::MemoizerKind, FluentArgs, FluentValue,
+};
+
+/// Function callable from Fluent messages.
+///
+/// Takes the positional and named arguments of the call.
+pub type FluentFunction =
+    for<'a> fn(&[FluentValue<'a>], &FluentArgs) -> FluentValue<'a>;
+
+/// Install functions and the functions of the enabled features into a
+/// Fluent bundle.
+///
+/// Called by the bundles built by `leptos_fluent!` with the functions of
+/// its `functions` parameter. Functions already installed in the bundle are
+/// preserved and the passed functions take precedence over the functions of
+/// the features.
+///
+/// Loaders built by `fluent_templates::static_loader!` can install them
+/// from their `customise` closure:
+///
+/// ```rust,ignore
+/// static_loader! {
+///     static TRANSLATIONS = {
+///         locales: "./locales",
+///         fallback_language: "en",
+///         customise: |bundle| leptos_fluent::functions::add_functions_with(
+///             bundle,
+///             &[("SHOUT", shout)],
+///         ),
+///     };
+/// }
+/// ```
+pub fn add_functions_with<R, M: MemoizerKind>(
+    bundle: &mut FluentBundle<R, M>,
+    functions: &[(&'static str, FluentFunction)],
+) {
+    for (name, function) in functions {
+        _ = bundle.add_function(name, *function);
+    }
+    #[cfg(feature = "number")]
+    crate::number::add_number_function(bundle);
+    #[cfg(feature = "datetime")]
+    crate::datetime::add_datetime_functions(bundle);
+}
+
+/// Install the functions of the enabled features into a Fluent bundle.
+///
+/// Like [`add_functions_with`] without custom functions:
+///
+/// ```rust,ignore
+/// static_loader! {
+///     static TRANSLATIONS = {
+///         locales: "./locales",
+///         fallback_language: "en",
+///         customise: |bundle| leptos_fluent::functions::add_functions(bundle),
+///     };
+/// }
+/// ```
+pub fn add_functions<R, M: MemoizerKind>(bundle: &mut FluentBundle<R, M>) {
+    add_functions_with(bundle, &[]);
+}
@@ -0,0 +1,29 @@
+//! Helpers to use the `Intl` formatters of the browser.
+
+use js_sys::{Array, Function, Object, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Construct an `Intl` formatter like `Intl.NumberFormat`.
+///
+/// The constructors throw with invalid options, like unknown units or
+/// time zones, so they're called through `Reflect` to catch the exceptions.
+pub fn construct<T: JsCast>(
+    name: &str,
+    locale: &str,
+    options: &Object,
+) -> Option<T> {
+    let constructor = Reflect::get(&js_sys::global(), &"Intl".into())
+        .and_then(|intl| Reflect::get(&intl, &name.into()))
+        .ok()?
+        .dyn_into::<Function>()
+        .ok()?;
+    let locales = Array::of1(&locale.into());
+    Reflect::construct(&constructor, &Array::of2(&locales, options))
+        .ok()
+        .map(JsCast::unchecked_into)
+}
+
+/// Set an option in an options object.
+pub fn set_option(options: &Object, key: &str, value: impl Into<JsValue>) {
+    _ = Reflect::set(options, &key.into(), &value.into());
+}
@@ -230,6 +230,7 @@
 //! - **Server Side Rendering**: `ssr`
 //! - **Actix Web integration**: `actix`
 //! - **Axum integration**: `axum`
+//! - **Leptos router integration**: `router`
 //! - **Nightly toolchain**: `nightly`
 //! - **Desktop applications**: `system`
 //! - **JSON languages file**: `json`
@@ -252,15 +253,34 @@
 //! [book]: https://mondeja.github.io/leptos-fluent/latest/
 //! [documentation]: https://docs.rs/leptos-fluent
 
+pub mod components;
 #[doc(hidden)]
 pub mod cookie;
 #[cfg(feature = "system")]
 #[doc(hidden)]
 pub mod data_file;
+#[cfg(feature = "datetime")]
+pub mod datetime;
+#[cfg(not(feature = "ssr"))]
+mod fetch;
+pub mod functions;
 #[doc(hidden)]
 pub mod http_header;
+#[cfg(all(
+    target_arch = "wasm32",
+    any(feature = "number", feature = "datetime")
+))]
+mod intl;
 #[doc(hidden)]
 pub mod local_storage;
+pub mod negotiation;
+#[cfg(feature = "number")]
+pub mod number;
+pub mod pseudo;
+#[cfg(feature = "router")]
+pub mod routes;
+#[cfg(feature = "ssr")]
+pub mod server;
 #[doc(hidden)]
 pub mod session_storage;
 #[doc(hidden)]
@@ -284,22 +304,32 @@ pub mod __reexports {
     pub use web_sys;
 }
 
-pub use leptos_fluent_macros::leptos_fluent;
+pub use leptos_fluent_macros::{
+    leptos_fluent, messages, server_i18n, static_loader,
+};
 
 use core::hash::{Hash, Hasher};
 use core::ops::Deref;
 use core::str::FromStr;
-use fluent_bundle::FluentValue;
+use fluent_bundle::{
+    bundle::FluentBundle, memoizer::MemoizerKind, FluentArgs, FluentError,
+    FluentResource, FluentValue,
+};
+use fluent_langneg::{negotiate_languages, NegotiationStrategy};
 use fluent_templates::{loader::Loader, LanguageIdentifier, StaticLoader};
 #[cfg(feature = "nightly")]
 use leptos::prelude::Set;
 use leptos::{
     attr::AttributeValue,
-    prelude::{guards::ReadGuard, Get, Read, RwSignal, Signal, Update, With},
+    prelude::{
+        guards::ReadGuard, Get, GetUntracked, GetValue, LocalResource, Read,
+        RwSignal, SetValue, Signal, StoredValue, Track, Update, UpdateValue,
+        With, WithValue,
+    },
 };
-use std::borrow::Cow;
+use std::borrow::{Borrow, Cow};
 use std::collections::HashMap;
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 
 /// Direction of the text
 #[derive(Debug)]
@@ -330,7 +360,20 @@ impl core::fmt::Display for WritingDirection {
 }
 
 /// Each language supported by your application.
+///
+/// Languages are usually generated by `leptos_fluent!`. To define them
+/// manually, use [`Language::new`] and the `with_*` methods:
+///
+/// ```rust
+/// use leptos_fluent::{Language, WritingDirection};
+///
+/// static ES_MX: Language =
+///     Language::new("es-MX", "Español (México)", &WritingDirection::Ltr)
+///         .with_flag("🇲🇽")
+///         .with_fallbacks(&["es", "en"]);
+/// ```
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub struct Language {
     /// Language identifier
     ///
@@ -347,6 +390,119 @@ pub struct Language {
     pub flag: Option<&'static str>,
     /// Optional script subtag for languages with multiple scripts (e.g. `Latn`).
     pub script: Option<&'static str>,
+    /// Identifiers of the languages to fallback to, in order, when a
+    /// message is not found for this language.
+    ///
+    /// Defined with the `fallback_languages` parameter of `leptos_fluent!`
+    /// or in the languages file.
+    pub fallbacks: &'static [&'static str],
+    /// Parsed identifier of the language.
+    ///
+    /// Precomputed by `leptos_fluent!` to avoid parsing `id` on each
+    /// translation. When `None`, `id` is parsed the first time it's needed.
+    pub identifier: Option<LanguageIdentifier>,
+    /// Names of the language in other languages of the application, by
+    /// identifier of the other language.
+    ///
+    /// Embedded by `leptos_fluent!` from the CLDR when the `displaynames`
+    /// feature is enabled.
+    pub display_names: &'static [(&'static str, &'static str)],
+}
+
+impl Language {
+    /// Create a language without flag, script, fallbacks nor display names.
+    pub const fn new(
+        id: &'static str,
+        name: &'static str,
+        dir: &'static WritingDirection,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            dir,
+            flag: None,
+            script: None,
+            fallbacks: &[],
+            identifier: None,
+            display_names: &[],
+        }
+    }
+
+    /// Create a language with its identifier parsed at compile time.
+    ///
+    /// Used by `leptos_fluent!` to precompute the identifiers of the
+    /// languages.
+    #[doc(hidden)]
+    pub const fn with_parsed_identifier(
+        id: &'static str,
+        identifier: LanguageIdentifier,
+        name: &'static str,
+        dir: &'static WritingDirection,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            dir,
+            flag: None,
+            script: None,
+            fallbacks: &[],
+            identifier: Some(identifier),
+            display_names: &[],
+        }
+    }
+
+    /// Set the flag of the language.
+    pub const fn with_flag(mut self, flag: &'static str) -> Self {
+        self.flag = Some(flag);
+        self
+    }
+
+    /// Set the script subtag of the language.
+    pub const fn with_script(mut self, script: &'static str) -> Self {
+        self.script = Some(script);
+        self
+    }
+
+    /// Set the identifiers of the languages to fallback to.
+    pub const fn with_fallbacks(
+        mut self,
+        fallbacks: &'static [&'static str],
+    ) -> Self {
+        self.fallbacks = fallbacks;
+        self
+    }
+
+    /// Set the names of the language in other languages, by identifier of
+    /// the other language.
+    pub const fn with_display_names(
+        mut self,
+        display_names: &'static [(&'static str, &'static str)],
+    ) -> Self {
+        self.display_names = display_names;
+        self
+    }
+
+    /// Name of the language in another language.
+    ///
+    /// Returns the name from the CLDR embedded with the `displaynames`
+    /// feature, or [`Language::name`] if it's not available or if the
+    /// other language is this one.
+    ///
+    /// ```rust,ignore
+    /// use leptos_fluent::I18n;
+    ///
+    /// let i18n = expect_context::<I18n>();
+    /// for lang in i18n.languages {
+    ///     // "Spanish" while the English language is active
+    ///     leptos::logging::log!("{}", lang.name_in(i18n.language.get()));
+    /// }
+    /// ```
+    pub fn name_in(&self, language: &Language) -> &'static str {
+        self.display_names
+            .iter()
+            .find(|(id, _)| *id == language.id)
+            .map_or(self.name, |(_, name)| name)
+    }
 }
 
 impl PartialEq for Language {
@@ -394,12 +550,10 @@ impl FromStr for Language {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        language_from_str_between_languages(
-            s,
-            leptos::prelude::expect_context::<I18n>().languages,
-        )
-        .ok_or(())
-        .cloned()
+        let i18n = leptos::prelude::expect_context::<I18n>();
+        l_with_strategy(s, i18n.languages, i18n.negotiation_strategy())
+            .ok_or(())
+            .cloned()
     }
 }
 
@@ -468,6 +622,301 @@ impl AttributeValue for &&Language {
     impl_attr_value_for_language!();
 }
 
+/// Kind of error raised when translating a message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TranslationErrorKind {
+    /// The message was not found in any translation.
+    MissingMessage,
+    /// The message was found but Fluent raised errors resolving it,
+    /// like missing variables or invalid function calls.
+    Format(String),
+}
+
+/// Error resolving a message in the translations.
+enum ResolveError {
+    /// The message was not found.
+    MissingMessage,
+    /// Fluent raised errors formatting the message.
+    Format {
+        /// First error raised by Fluent.
+        error: String,
+        /// Message formatted by Fluent despite the errors.
+        formatted: String,
+    },
+}
+
+impl From<ResolveError> for TranslationErrorKind {
+    fn from(error: ResolveError) -> Self {
+        match error {
+            ResolveError::MissingMessage => Self::MissingMessage,
+            ResolveError::Format { error, .. } => Self::Format(error),
+        }
+    }
+}
+
+/// Error raised when translating a message.
+///
+/// Passed to the handler defined with the `on_error` parameter of
+/// `leptos_fluent!` or with [`I18n::set_on_error`].
+#[derive(Debug)]
+pub struct TranslationError<'a> {
+    /// Identifier of the message.
+    pub text_id: &'a str,
+    /// Language in which the message was requested.
+    pub language: &'static Language,
+    /// Arguments passed to the message, if any.
+    pub args: Option<&'a HashMap<Cow<'static, str>, FluentValue<'a>>>,
+    /// Kind of the error.
+    pub kind: TranslationErrorKind,
+}
+
+/// Handler for translation errors.
+///
+/// Returns the string to display instead of the translation.
+pub type TranslationErrorHandler =
+    Arc<dyn Fn(&TranslationError) -> String + Send + Sync>;
+
+/// Error raised adding a Fluent resource with [`I18n::add_resource`].
+#[derive(Debug)]
+pub enum AddResourceError {
+    /// The language identifier is not valid.
+    InvalidLanguage(String),
+    /// The Fluent source contains syntax errors.
+    Parse(Vec<FluentError>),
+}
+
+/// Translations fetched on demand when the language changes.
+///
+/// Defined by the `lazy_translations_url` parameter of `leptos_fluent!`.
+/// Only the translations of the fallback language are embedded in the
+/// binary, and the FTL files of other languages are fetched from
+/// `{url}/{language}/{file}` the first time that they're needed.
+#[derive(Clone, Copy, Debug)]
+pub struct LazyTranslations {
+    /// URL prefix from which the FTL files are fetched.
+    pub url: &'static str,
+    /// Identifier of the language whose translations are embedded.
+    pub fallback_language: &'static str,
+    /// Paths of the FTL files of each language, relative to the directory
+    /// of the language.
+    pub files: &'static [(&'static str, &'static [&'static str])],
+    /// Core Fluent resource shared by all the languages, if any.
+    pub core: Option<&'static str>,
+}
+
+/// Bundle holding the resources added at runtime for a language.
+type RuntimeBundle =
+    fluent_bundle::concurrent::FluentBundle<Arc<FluentResource>>;
+
+/// Bundles holding the resources added at runtime for a language, with and
+/// without isolating placeables.
+struct RuntimeBundles {
+    isolating: RuntimeBundle,
+    unisolated: RuntimeBundle,
+}
+
+impl RuntimeBundles {
+    /// Bundle formatting the messages with or without isolating placeables.
+    fn get(&self, use_isolating: bool) -> &RuntimeBundle {
+        match use_isolating {
+            true => &self.isolating,
+            false => &self.unisolated,
+        }
+    }
+}
+
+/// Bundles of a static loader built by `leptos_fluent!`, by language.
+#[doc(hidden)]
+pub type StaticBundles = HashMap<
+    LanguageIdentifier,
+    fluent_templates::FluentBundle<&'static FluentResource>,
+>;
+
+/// Bundles of a static loader, with and without isolating placeables.
+///
+/// The bundles without isolation are only built when they're used.
+#[derive(Clone, Copy)]
+enum LoaderBundles {
+    /// Bundles of a loader built by `leptos_fluent!`.
+    Static {
+        isolating: &'static LazyLock<StaticBundles>,
+        unisolated: &'static LazyLock<StaticBundles>,
+    },
+    /// Bundles built from the sources of a loader of [`static_loader!`].
+    Sources(&'static SourcesBundles),
+}
+
+impl LoaderBundles {
+    /// Bundles formatting the messages with or without isolating placeables.
+    fn get(self, use_isolating: bool) -> &'static StaticBundles {
+        match (self, use_isolating) {
+            (Self::Static { isolating, .. }, true) => isolating,
+            (Self::Static { unisolated, .. }, false) => unisolated,
+            (Self::Sources(bundles), _) => bundles.get(use_isolating),
+        }
+    }
+}
+
+/// Resources and `customise` closure of a loader built by
+/// [`static_loader!`].
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct LoaderSources {
+    /// Resources of the loader by language.
+    pub resources:
+        &'static LazyLock<HashMap<LanguageIdentifier, Vec<FluentResource>>>,
+    /// Core resource shared by all the languages, if any.
+    pub core_resource: &'static LazyLock<Option<FluentResource>>,
+    /// `customise` closure of the loader.
+    pub customise:
+        fn(&mut fluent_templates::FluentBundle<&'static FluentResource>),
+}
+
+/// Sources of the loaders built by [`static_loader!`].
+static LOADER_SOURCES: std::sync::RwLock<
+    Vec<(&'static LazyLock<StaticLoader>, LoaderSources)>,
+> = std::sync::RwLock::new(Vec::new());
+
+/// Bundles built from the sources of the loaders with the functions of the
+/// contexts that use them.
+///
+/// Built once for each loader and functions of `leptos_fluent!`, so they're
+/// shared by all the contexts created by the same macro call.
+static SOURCES_BUNDLES: std::sync::Mutex<Vec<&'static SourcesBundles>> =
+    std::sync::Mutex::new(Vec::new());
+
+impl LoaderSources {
+    /// Register the sources of a loader.
+    ///
+    /// Called by [`static_loader!`] when the loader is initialized.
+    pub fn register(self, loader: &'static LazyLock<StaticLoader>) {
+        if let Ok(mut sources) = LOADER_SOURCES.write() {
+            sources.push((loader, self));
+        }
+    }
+
+    /// Sources of a loader, if it was built by [`static_loader!`].
+    fn of(loader: &'static LazyLock<StaticLoader>) -> Option<Self> {
+        _ = LazyLock::force(loader);
+        LOADER_SOURCES.read().ok().and_then(|sources| {
+            sources
+                .iter()
+                .find(|(registered, _)| core::ptr::eq(*registered, loader))
+                .map(|(_, sources)| *sources)
+        })
+    }
+}
+
+/// Bundles built from the sources of a loader with some functions, with and
+/// without isolating placeables.
+struct SourcesBundles {
+    sources: LoaderSources,
+    functions: &'static [(&'static str, functions::FluentFunction)],
+    isolating: std::sync::OnceLock<StaticBundles>,
+    unisolated: std::sync::OnceLock<StaticBundles>,
+}
+
+impl SourcesBundles {
+    /// Bundles of a loader with some functions, built the first time
+    /// they're requested.
+    fn get_or_new(
+        sources: LoaderSources,
+        functions: &'static [(&'static str, functions::FluentFunction)],
+    ) -> &'static Self {
+        let mut sources_bundles = SOURCES_BUNDLES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Some(bundles) = sources_bundles.iter().find(|bundles| {
+            core::ptr::eq(bundles.sources.resources, sources.resources)
+                && core::ptr::eq(bundles.functions, functions)
+        }) {
+            return bundles;
+        }
+        let bundles = Box::leak(Box::new(Self {
+            sources,
+            functions,
+            isolating: std::sync::OnceLock::new(),
+            unisolated: std::sync::OnceLock::new(),
+        }));
+        sources_bundles.push(bundles);
+        bundles
+    }
+
+    /// Bundles formatting the messages with or without isolating placeables.
+    fn get(&'static self, use_isolating: bool) -> &'static StaticBundles {
+        let cell = match use_isolating {
+            true => &self.isolating,
+            false => &self.unisolated,
+        };
+        cell.get_or_init(|| {
+            fluent_templates::loader::build_bundles(
+                self.sources.resources,
+                self.sources.core_resource.as_ref(),
+                |bundle| {
+                    functions::add_functions_with(bundle, self.functions);
+                    (self.sources.customise)(bundle);
+                    if !use_isolating {
+                        bundle.set_use_isolating(false);
+                    }
+                },
+            )
+        })
+    }
+}
+
+/// Fallback chain of a language negotiated with the locales of the loaders.
+struct FallbackChain {
+    /// Identifiers of the language and its fallbacks, in order.
+    languages: Vec<LanguageIdentifier>,
+    /// Negotiated locales of each loader for each language of the chain.
+    locales: Vec<Vec<Vec<LanguageIdentifier>>>,
+    /// Bundles of each loader, if they were built by `leptos_fluent!`.
+    bundles: Vec<Option<LoaderBundles>>,
+}
+
+/// Translations of the messages without arguments, fallback chains and
+/// route slugs resolved by language.
+///
+/// Only valid while the loaders are the ones that resolved them. Messages
+/// are cleared when resources are added at runtime.
+#[derive(Default)]
+struct MessagesCache {
+    translations: Vec<&'static LazyLock<StaticLoader>>,
+    messages: HashMap<(&'static str, bool), HashMap<String, String>>,
+    chains: HashMap<&'static str, Arc<FallbackChain>>,
+    #[cfg(feature = "router")]
+    route_slugs: HashMap<&'static str, Arc<routes::RouteSlugs>>,
+}
+
+impl MessagesCache {
+    /// Whether the messages were resolved with some loaders.
+    fn is_valid_for(
+        &self,
+        translations: &[&'static LazyLock<StaticLoader>],
+    ) -> bool {
+        self.translations.len() == translations.len()
+            && self
+                .translations
+                .iter()
+                .zip(translations)
+                .all(|(a, b)| core::ptr::eq(*a, *b))
+    }
+
+    /// Clear the cache if it was not resolved with some loaders.
+    fn validate_for(
+        &mut self,
+        translations: &[&'static LazyLock<StaticLoader>],
+    ) {
+        if !self.is_valid_for(translations) {
+            self.translations = translations.to_vec();
+            self.messages.clear();
+            self.chains.clear();
+            #[cfg(feature = "router")]
+            self.route_slugs.clear();
+        }
+    }
+}
+
 /// Internationalization context.
 ///
 /// Used to provide the current language, the available languages and all
@@ -481,8 +930,39 @@ pub struct I18n {
     pub languages: &'static [&'static Language],
     /// Signal with a vector of fluent-templates static loaders.
     pub translations: Signal<Vec<&'static LazyLock<StaticLoader>>>,
-    /// Cache for language identifiers.
-    language_id_cache: RwSignal<HashMap<&'static str, LanguageIdentifier>>,
+    /// Cache for language identifiers not precomputed in `languages`.
+    language_id_cache: StoredValue<HashMap<&'static str, LanguageIdentifier>>,
+    /// Cache for the translations of messages without arguments.
+    messages_cache: StoredValue<MessagesCache>,
+    /// Handler for translation errors.
+    on_error: StoredValue<Option<TranslationErrorHandler>>,
+    /// Bundles of the static loaders built by `leptos_fluent!`.
+    static_bundles:
+        StoredValue<Vec<(&'static LazyLock<StaticLoader>, LoaderBundles)>>,
+    /// Canonical route segments translated by `route-*` messages.
+    #[cfg(feature = "router")]
+    route_segments: StoredValue<&'static [&'static str]>,
+    /// Functions installed in the bundles of the resources added at runtime.
+    functions:
+        StoredValue<&'static [(&'static str, functions::FluentFunction)]>,
+    /// Bundles with the resources added at runtime by language.
+    resources: RwSignal<HashMap<LanguageIdentifier, RuntimeBundles>>,
+    /// Translations fetched on demand.
+    lazy_translations: StoredValue<Option<LazyTranslations>>,
+    /// Resource that fetches the translations of the language of this
+    /// context.
+    lazy_translations_resource: StoredValue<Option<LocalResource<()>>>,
+    /// Identifiers of the languages whose translations have been fetched.
+    #[cfg(not(feature = "ssr"))]
+    lazy_loaded_languages: StoredValue<Vec<&'static str>>,
+    /// Identifiers of the languages whose translations are being fetched.
+    loading: RwSignal<Vec<&'static str>>,
+    /// Whether placeables are wrapped in Unicode isolation marks.
+    use_isolating: StoredValue<bool>,
+    /// Override of `use_isolating` for the translations of this copy.
+    use_isolating_override: Option<bool>,
+    /// Strategy used to match language codes against `languages`.
+    negotiation_strategy: StoredValue<negotiation::NegotiationStrategy>,
 }
 
 impl I18n {
@@ -496,10 +976,440 @@ impl I18n {
             language,
             languages,
             translations,
-            language_id_cache: RwSignal::new(HashMap::new()),
+            language_id_cache: StoredValue::new(HashMap::new()),
+            messages_cache: StoredValue::new(MessagesCache::default()),
+            on_error: StoredValue::new(None),
+            static_bundles: StoredValue::new(Vec::new()),
+            #[cfg(feature = "router")]
+            route_segments: StoredValue::new(&[]),
+            functions: StoredValue::new(&[]),
+            resources: RwSignal::new(HashMap::new()),
+            lazy_translations: StoredValue::new(None),
+            lazy_translations_resource: StoredValue::new(None),
+            #[cfg(not(feature = "ssr"))]
+            lazy_loaded_languages: StoredValue::new(Vec::new()),
+            loading: RwSignal::new(Vec::new()),
+            use_isolating: StoredValue::new(true),
+            use_isolating_override: None,
+            negotiation_strategy: StoredValue::new(
+                negotiation::NegotiationStrategy::default(),
+            ),
+        }
+    }
+
+    /// Fetch translations on demand when the language changes.
+    ///
+    /// Called by `leptos_fluent!` when the `lazy_translations_url` parameter
+    /// is defined. The translations of the fallback language must be added
+    /// with [`I18n::add_resource`].
+    ///
+    /// Not available on SSR, where `leptos_fluent!` rejects
+    /// `lazy_translations_url`.
+    #[cfg(not(feature = "ssr"))]
+    pub fn set_lazy_translations(&self, lazy_translations: LazyTranslations) {
+        self.lazy_loaded_languages.update_value(|languages| {
+            languages.push(lazy_translations.fallback_language)
+        });
+        self.lazy_translations.set_value(Some(lazy_translations));
+        self.track_lazy_translations(lazy_translations);
+    }
+
+    /// Create the resource that fetches the translations of the language of
+    /// this context when it changes.
+    #[cfg(not(feature = "ssr"))]
+    fn track_lazy_translations(&self, lazy_translations: LazyTranslations) {
+        let i18n = *self;
+        let resource = LocalResource::new(move || {
+            let language = i18n.language.get();
+            i18n.fetch_lazy_translations(lazy_translations, language)
+        });
+        self.lazy_translations_resource.set_value(Some(resource));
+    }
+
+    /// Fetch and add the translations of a language if not fetched yet.
+    #[cfg(not(feature = "ssr"))]
+    async fn fetch_lazy_translations(
+        self,
+        lazy_translations: LazyTranslations,
+        language: &'static Language,
+    ) {
+        if self
+            .lazy_loaded_languages
+            .with_value(|languages| languages.contains(&language.id))
+            || self.loading.get_untracked().contains(&language.id)
+        {
+            return;
+        }
+        let Some((_, files)) = lazy_translations
+            .files
+            .iter()
+            .find(|(id, _)| *id == language.id)
+        else {
+            return;
+        };
+
+        self.loading.update(|ids| ids.push(language.id));
+        let mut fetched = true;
+        let mut sources = lazy_translations
+            .core
+            .map(str::to_string)
+            .into_iter()
+            .collect::<Vec<_>>();
+        for file in *files {
+            let url = format!(
+                "{}/{}/{}",
+                lazy_translations.url.trim_end_matches('/'),
+                language.id,
+                file
+            );
+            match fetch::text(&url).await {
+                Some(source) => sources.push(source),
+                None => fetched = false,
+            }
+        }
+        for source in sources {
+            if let Err(_error) = self.add_resource(language.id, source) {
+                #[cfg(feature = "tracing")]
+                tracing::error!(
+                    "Invalid translations fetched for language \"{}\": {:?}",
+                    language.id,
+                    _error
+                );
+            }
+        }
+        // languages that failed to be fetched are retried when selected again
+        if fetched {
+            self.lazy_loaded_languages
+                .update_value(|languages| languages.push(language.id));
+        }
+        self.loading
+            .update(|ids| ids.retain(|id| *id != language.id));
+    }
+
+    /// Signal that is `true` while the translations of the current language
+    /// are being fetched.
+    ///
+    /// Translations are only fetched when the `lazy_translations_url`
+    /// parameter of `leptos_fluent!` is defined.
+    pub fn loading(&self) -> Signal<bool> {
+        let (language, loading) = (self.language, self.loading);
+        Signal::derive(move || {
+            let id = language.get().id;
+            loading.with(|ids| ids.contains(&id))
+        })
+    }
+
+    /// Resource that fetches the translations of the current language.
+    ///
+    /// Defined when the `lazy_translations_url` parameter of `leptos_fluent!`
+    /// is defined. Contexts derived with [`I18n::scope`] have their own
+    /// resource, which fetches the translations of their language. Read it
+    /// inside a `<Suspense/>` to render its fallback while the translations
+    /// of a new language are being fetched:
+    ///
+    /// ```rust,ignore
+    /// use leptos::prelude::*;
+    /// use leptos_fluent::{move_tr, I18n};
+    ///
+    /// let i18n = expect_context::<I18n>();
+    ///
+    /// view! {
+    ///     <Suspense fallback=|| "Loading...">
+    ///         {move || i18n.lazy_translations().map(|resource| resource.get())}
+    ///         <p>{move_tr!("hello-world")}</p>
+    ///     </Suspense>
+    /// }
+    /// ```
+    ///
+    /// Always `None` on SSR, where the translations are not fetched.
+    pub fn lazy_translations(&self) -> Option<LocalResource<()>> {
+        self.lazy_translations_resource.get_value()
+    }
+
+    /// Add a Fluent resource for a language at runtime.
+    ///
+    /// Useful to load translations from sources that are not available at
+    /// compile time, like a CMS. Messages of resources added at runtime take
+    /// precedence over the static translations of the same language, and
+    /// messages of the last added resources override previous ones. The
+    /// fallback chain of the language is traversed in order, so static
+    /// translations of a language take precedence over resources added for
+    /// its fallback languages. Resources added for the fallback language of
+    /// the static translations are used as a last resort.
+    ///
+    /// Translations using the context are updated when a resource is added.
+    /// If the source contains syntax errors, the resource is not added. The
+    /// texts of the resources added for [pseudolocales](pseudo) are
+    /// transformed.
+    ///
+    /// ```rust,ignore
+    /// use leptos::prelude::expect_context;
+    /// use leptos_fluent::I18n;
+    ///
+    /// let i18n = expect_context::<I18n>();
+    /// i18n.add_resource("es", "promo-banner = ¡Rebajas de verano!".to_string())
+    ///     .expect("Invalid Fluent resource");
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip(self, source), err(Debug))
+    )]
+    pub fn add_resource(
+        &self,
+        language: &str,
+        source: String,
+    ) -> Result<(), AddResourceError> {
+        let lang_id = LanguageIdentifier::from_str(language)
+            .map_err(|_| AddResourceError::InvalidLanguage(language.into()))?;
+        let resource =
+            FluentResource::try_new(source).map_err(|(_, errors)| {
+                AddResourceError::Parse(
+                    errors.into_iter().map(FluentError::from).collect(),
+                )
+            })?;
+        self.add_resource_to_bundle(lang_id, Arc::new(resource));
+        Ok(())
+    }
+
+    /// Add a parsed Fluent resource for a language at runtime.
+    ///
+    /// Used by `leptos_fluent!` to share the resources parsed once between
+    /// the contexts, like those of the pseudolocales.
+    #[doc(hidden)]
+    pub fn add_parsed_resource(
+        &self,
+        language: &str,
+        resource: Arc<FluentResource>,
+    ) -> Result<(), AddResourceError> {
+        let lang_id = LanguageIdentifier::from_str(language)
+            .map_err(|_| AddResourceError::InvalidLanguage(language.into()))?;
+        self.add_resource_to_bundle(lang_id, resource);
+        Ok(())
+    }
+
+    /// Add a resource to the runtime bundle of a language.
+    fn add_resource_to_bundle(
+        &self,
+        lang_id: LanguageIdentifier,
+        resource: Arc<FluentResource>,
+    ) {
+        self.messages_cache.update_value(|cache| {
+            cache.messages.clear();
+            #[cfg(feature = "router")]
+            cache.route_slugs.clear();
+        });
+        self.resources.update(|resources| {
+            let bundles =
+                resources.entry(lang_id.clone()).or_insert_with(|| {
+                    let new_bundle = |use_isolating| {
+                        let mut bundle =
+                            RuntimeBundle::new_concurrent(
+                                vec![lang_id.clone()],
+                            );
+                        functions::add_functions_with(
+                            &mut bundle,
+                            self.functions.get_value(),
+                        );
+                        bundle.set_transform(pseudo::transform(&lang_id));
+                        bundle.set_use_isolating(use_isolating);
+                        bundle
+                    };
+                    RuntimeBundles {
+                        isolating: new_bundle(true),
+                        unisolated: new_bundle(false),
+                    }
+                });
+            bundles
+                .isolating
+                .add_resource_overriding(Arc::clone(&resource));
+            bundles.unisolated.add_resource_overriding(resource);
+        });
+    }
+
+    /// Name of a language in the current language.
+    ///
+    /// See [`Language::name_in`].
+    pub fn language_display_name(&self, language: &Language) -> &'static str {
+        language.name_in(self.language.get())
+    }
+
+    /// Set whether placeables are wrapped in Unicode isolation marks.
+    ///
+    /// Fluent wraps placeables in FSI (U+2068) and PDI (U+2069) marks to
+    /// isolate their direction from the surrounding text, which is needed
+    /// for values like user names in right-to-left languages. Disabling it
+    /// prevents invisible characters from leaking into input values,
+    /// clipboard copies and snapshot tests. Enabled by default.
+    ///
+    /// Applies to the bundles built by `leptos_fluent!`, to the loaders
+    /// passed with its `translations` parameter that were built by
+    /// [`static_loader!`] and to the resources added with
+    /// [`I18n::add_resource`]. Loaders built by
+    /// `fluent_templates::static_loader!` format their messages with their
+    /// own bundles, so call `bundle.set_use_isolating(false)` in their
+    /// `customise` closure instead.
+    pub fn set_use_isolating(&self, use_isolating: bool) {
+        self.use_isolating.set_value(use_isolating);
+    }
+
+    /// Whether placeables are wrapped in Unicode isolation marks in the
+    /// translations of this context.
+    pub fn use_isolating(&self) -> bool {
+        self.use_isolating_override
+            .unwrap_or_else(|| self.use_isolating.get_value())
+    }
+
+    /// Copy of the context with an override for the isolation of placeables
+    /// in its translations.
+    ///
+    /// Used by the `use_isolating` option of the translation macros:
+    ///
+    /// ```rust,ignore
+    /// use leptos_fluent::tr;
+    ///
+    /// tr!(use_isolating = false, "greeting", { "name" => "Jane" })
+    /// ```
+    pub fn with_use_isolating(self, use_isolating: bool) -> Self {
+        Self {
+            use_isolating_override: Some(use_isolating),
+            ..self
         }
     }
 
+    /// Derived context with its own language signal.
+    ///
+    /// The derived context shares the languages, translations, resources
+    /// and caches of this one, but changing its language doesn't change the
+    /// language of this context nor is synchronized with any source like
+    /// cookies or storages. Used by the
+    /// [`<LanguageScope/>`](crate::components::LanguageScope) component to
+    /// render subtrees in other languages.
+    pub fn scope(&self, language: &'static Language) -> Self {
+        let scoped = Self {
+            language: RwSignal::new(language),
+            lazy_translations_resource: StoredValue::new(None),
+            ..*self
+        };
+        #[cfg(not(feature = "ssr"))]
+        if let Some(lazy_translations) = self.lazy_translations.get_value() {
+            scoped.track_lazy_translations(lazy_translations);
+        }
+        scoped
+    }
+
+    /// Set the handler for translation errors.
+    ///
+    /// The handler is called when a message is not found in any translation
+    /// or when Fluent raises errors resolving it. The string returned by the
+    /// handler is displayed instead of the translation.
+    ///
+    /// ```rust,ignore
+    /// use leptos::prelude::expect_context;
+    /// use leptos_fluent::{I18n, TranslationError};
+    ///
+    /// let tracker = ErrorTracker::new();
+    /// expect_context::<I18n>().set_on_error(move |error: &TranslationError| {
+    ///     tracker.report(error);
+    ///     String::new()
+    /// });
+    /// ```
+    pub fn set_on_error(
+        &self,
+        handler: impl Fn(&TranslationError) -> String + Send + Sync + 'static,
+    ) {
+        self.on_error.set_value(Some(Arc::new(handler)));
+    }
+
+    /// Associate a static loader built by `leptos_fluent!` with its bundles,
+    /// with and without isolating placeables.
+    ///
+    /// Messages of loaders with bundles are formatted directly with
+    /// Fluent, so their formatting errors are reported to the `on_error`
+    /// handler with the errors raised by Fluent.
+    #[doc(hidden)]
+    pub fn add_static_bundles(
+        &self,
+        loader: &'static LazyLock<StaticLoader>,
+        bundles: &'static LazyLock<StaticBundles>,
+        unisolated_bundles: &'static LazyLock<StaticBundles>,
+    ) {
+        self.static_bundles.update_value(|static_bundles| {
+            static_bundles.push((
+                loader,
+                LoaderBundles::Static {
+                    isolating: bundles,
+                    unisolated: unisolated_bundles,
+                },
+            ))
+        });
+        self.messages_cache
+            .update_value(|cache| *cache = MessagesCache::default());
+    }
+
+    /// Format the messages of a loader passed with the `translations`
+    /// parameter of `leptos_fluent!` with bundles built from its own
+    /// sources, installing the functions of this context and applying its
+    /// isolation of placeables.
+    ///
+    /// Only loaders built by [`static_loader!`] expose their sources. The
+    /// messages of other loaders are formatted by their own bundles.
+    #[doc(hidden)]
+    pub fn add_loader(&self, loader: &'static LazyLock<StaticLoader>) {
+        let Some(sources) = LoaderSources::of(loader) else {
+            return;
+        };
+        let bundles =
+            SourcesBundles::get_or_new(sources, self.functions.get_value());
+        self.static_bundles.update_value(|static_bundles| {
+            static_bundles.push((loader, LoaderBundles::Sources(bundles)))
+        });
+        self.messages_cache
+            .update_value(|cache| *cache = MessagesCache::default());
+    }
+
+    /// Set the canonical route segments translated by `route-*` messages.
+    ///
+    /// Called by `leptos_fluent!` with the segments of the messages of its
+    /// `locales`.
+    #[cfg(feature = "router")]
+    #[doc(hidden)]
+    pub fn set_route_segments(&self, segments: &'static [&'static str]) {
+        self.route_segments.set_value(segments);
+        self.messages_cache
+            .update_value(|cache| cache.route_slugs.clear());
+    }
+
+    /// Set the functions installed in the bundles of the resources added
+    /// with [`I18n::add_resource`].
+    ///
+    /// Called by `leptos_fluent!` with the functions of its `functions`
+    /// parameter.
+    #[doc(hidden)]
+    pub fn set_functions(
+        &self,
+        functions: &'static [(&'static str, functions::FluentFunction)],
+    ) {
+        self.functions.set_value(functions);
+    }
+
+    /// Set the strategy used to match language codes against the available
+    /// languages when parsing a [`Language`].
+    ///
+    /// Called by `leptos_fluent!` with its `negotiation_strategy` parameter.
+    #[doc(hidden)]
+    pub fn set_negotiation_strategy(
+        &self,
+        strategy: negotiation::NegotiationStrategy,
+    ) {
+        self.negotiation_strategy.set_value(strategy);
+    }
+
+    /// Strategy used to match language codes against the available
+    /// languages of this context.
+    pub fn negotiation_strategy(&self) -> negotiation::NegotiationStrategy {
+        self.negotiation_strategy.get_value()
+    }
+
     /// Get meta information about the i18n context.
     ///
     /// Useful to get at runtime the parameters that created the context
@@ -533,33 +1443,398 @@ impl I18n {
 
     fn get_language_identifier(
         &self,
-        lang: &'static Language,
+        lang_id: &'static str,
     ) -> Option<LanguageIdentifier> {
-        // Intenta obtener del cache primero
+        let precomputed = self
+            .languages
+            .iter()
+            .find(|language| language.id == lang_id)
+            .and_then(|language| language.identifier.clone());
+        if precomputed.is_some() {
+            return precomputed;
+        }
+
         let cached = self
             .language_id_cache
-            .with(|cache| cache.get(lang.id).cloned());
-
-        if let Some(id) = cached {
-            return Some(id);
+            .with_value(|cache| cache.get(lang_id).cloned());
+        if cached.is_some() {
+            return cached;
         }
 
-        // Si no está en cache, parsea e inserta
-        match LanguageIdentifier::from_str(lang.id) {
+        match LanguageIdentifier::from_str(lang_id) {
             Ok(id) => {
-                self.language_id_cache.update(|cache| {
-                    cache.insert(lang.id, id.clone());
+                self.language_id_cache.update_value(|cache| {
+                    cache.insert(lang_id, id.clone());
                 });
                 Some(id)
             }
             Err(_) => {
                 #[cfg(feature = "tracing")]
-                tracing::error!("Invalid language identifier \"{}\"", lang.id);
+                tracing::error!("Invalid language identifier \"{}\"", lang_id);
                 None
             }
         }
     }
 
+    /// Lookup a message in the translations for the current language.
+    ///
+    /// Messages without arguments are resolved once for each language and
+    /// served from a cache afterwards.
+    fn lookup(
+        &self,
+        text_id: &str,
+        args: Option<&HashMap<Cow<'static, str>, FluentValue>>,
+    ) -> Result<String, ResolveError> {
+        match args {
+            Some(args) => self.resolve(text_id, Some(args)),
+            None => self.resolve_cached(text_id),
+        }
+    }
+
+    /// Lookup a message only in the translations for the current language.
+    fn try_lookup(
+        &self,
+        text_id: &str,
+        args: Option<&HashMap<Cow<'static, str>, FluentValue>>,
+    ) -> Option<String> {
+        self.resolve_exact(text_id, args).ok()
+    }
+
+    /// Resolve a message without arguments using the messages cache.
+    fn resolve_cached(&self, text_id: &str) -> Result<String, ResolveError> {
+        let language = self.language.get();
+        let key = (language.id, self.use_isolating());
+        // subscribe to the resources added at runtime as a cache miss would
+        self.resources.track();
+        self.translations.with(|translations| {
+            let cached = self.messages_cache.with_value(|cache| {
+                cache
+                    .is_valid_for(translations)
+                    .then(|| cache.messages.get(&key)?.get(text_id))
+                    .flatten()
+                    .cloned()
+            });
+            if let Some(found) = cached {
+                return Ok(found);
+            }
+
+            let result = self.resolve(text_id, None);
+            if let Ok(found) = &result {
+                self.messages_cache.update_value(|cache| {
+                    cache.validate_for(translations);
+                    cache
+                        .messages
+                        .entry(key)
+                        .or_default()
+                        .insert(text_id.to_string(), found.clone());
+                });
+            }
+            result
+        })
+    }
+
+    /// Resolve a message in the translations for the current language.
+    fn resolve(
+        &self,
+        text_id: &str,
+        args: Option<&HashMap<Cow<'static, str>, FluentValue>>,
+    ) -> Result<String, ResolveError> {
+        self.resolve_in(self.language.get(), text_id, args)
+    }
+
+    /// Resolve a message only in the translations for the current language.
+    ///
+    /// Neither the fallback chain of the language nor the fallback
+    /// languages of the loaders are traversed.
+    fn resolve_exact(
+        &self,
+        text_id: &str,
+        args: Option<&HashMap<Cow<'static, str>, FluentValue>>,
+    ) -> Result<String, ResolveError> {
+        let language = self.language.get();
+        let Some(lang_id) = self.get_language_identifier(language.id) else {
+            return Err(ResolveError::MissingMessage);
+        };
+
+        let use_isolating = self.use_isolating();
+        let resources = self.resources.read();
+        self.translations.with(|translations| {
+            let chain = self.fallback_chain(language, translations);
+            if let Some(result) = resources.get(&lang_id).and_then(|bundles| {
+                format_message(bundles.get(use_isolating), text_id, args)
+            }) {
+                return result;
+            }
+            for (tr, bundles) in translations.iter().zip(&chain.bundles) {
+                let bundles = bundles.map(|bundles| bundles.get(use_isolating));
+                if let Some(result) =
+                    format_static_message(tr, bundles, &lang_id, text_id, args)
+                {
+                    return result;
+                }
+            }
+            Err(ResolveError::MissingMessage)
+        })
+    }
+
+    /// Slugs of the route segments in a language.
+    ///
+    /// Resolved once for each language and served from the messages cache
+    /// afterwards.
+    #[cfg(feature = "router")]
+    fn route_slugs(
+        &self,
+        language: &'static Language,
+    ) -> Arc<routes::RouteSlugs> {
+        // subscribe to the resources added at runtime as a cache miss would
+        self.resources.track();
+        self.translations.with(|translations| {
+            let cached = self.messages_cache.with_value(|cache| {
+                cache
+                    .is_valid_for(translations)
+                    .then(|| cache.route_slugs.get(language.id).cloned())
+                    .flatten()
+            });
+            if let Some(slugs) = cached {
+                return slugs;
+            }
+
+            let slugs = Arc::new(routes::RouteSlugs::new(
+                self,
+                language,
+                self.route_segments.get_value(),
+            ));
+            self.messages_cache.update_value(|cache| {
+                cache.validate_for(translations);
+                cache.route_slugs.insert(language.id, Arc::clone(&slugs));
+            });
+            slugs
+        })
+    }
+
+    /// Fallback chain of a language negotiated with the locales of some
+    /// loaders.
+    ///
+    /// Negotiated once for each language and served from the messages cache
+    /// afterwards.
+    fn fallback_chain(
+        &self,
+        language: &'static Language,
+        translations: &[&'static LazyLock<StaticLoader>],
+    ) -> Arc<FallbackChain> {
+        let cached = self.messages_cache.with_value(|cache| {
+            cache
+                .is_valid_for(translations)
+                .then(|| cache.chains.get(language.id).cloned())
+                .flatten()
+        });
+        if let Some(chain) = cached {
+            return chain;
+        }
+
+        let languages = core::iter::once(language.id)
+            .chain(language.fallbacks.iter().copied())
+            .filter_map(|id| self.get_language_identifier(id))
+            .collect::<Vec<_>>();
+        let locales = languages
+            .iter()
+            .map(|lang_id| {
+                translations
+                    .iter()
+                    .map(|tr| {
+                        let available = tr.locales().collect::<Vec<_>>();
+                        negotiate_languages(
+                            &[lang_id],
+                            &available,
+                            None,
+                            NegotiationStrategy::Filtering,
+                        )
+                        .into_iter()
+                        .map(|lang_id| (*lang_id).clone())
+                        .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+        let bundles = self.static_bundles.with_value(|static_bundles| {
+            translations
+                .iter()
+                .map(|tr| {
+                    static_bundles
+                        .iter()
+                        .find(|(loader, _)| core::ptr::eq(*loader, *tr))
+                        .map(|(_, bundles)| *bundles)
+                })
+                .collect()
+        });
+        let chain = Arc::new(FallbackChain {
+            languages,
+            locales,
+            bundles,
+        });
+
+        self.messages_cache.update_value(|cache| {
+            cache.validate_for(translations);
+            cache.chains.insert(language.id, Arc::clone(&chain));
+        });
+        chain
+    }
+
+    /// Resolve a message in the translations for a language.
+    ///
+    /// The fallback chain of the language is traversed before the default
+    /// fallback of the loaders, so `es-MX -> es -> en` resolves to `es`
+    /// when the message is not translated to `es-MX`. Only missing messages
+    /// are resolved in the next language, so the errors raised formatting
+    /// a message are reported for the first language that defines it.
+    fn resolve_in(
+        &self,
+        language: &'static Language,
+        text_id: &str,
+        args: Option<&HashMap<Cow<'static, str>, FluentValue>>,
+    ) -> Result<String, ResolveError> {
+        let lazy_fallback_language =
+            self.lazy_translations.with_value(|lazy| {
+                lazy.and_then(|lazy_translations| {
+                    self.get_language_identifier(
+                        lazy_translations.fallback_language,
+                    )
+                })
+            });
+
+        let use_isolating = self.use_isolating();
+        let resources = self.resources.read();
+        self.translations.with(|translations| {
+            let chain = self.fallback_chain(language, translations);
+            for (lang_id, locales) in chain.languages.iter().zip(&chain.locales)
+            {
+                if !resources.is_empty() {
+                    // pseudolocales are only used when they're requested
+                    let available = resources
+                        .keys()
+                        .filter(|key| {
+                            *key == lang_id || !pseudo::is_pseudolocale(key)
+                        })
+                        .collect::<Vec<_>>();
+                    let negotiated = negotiate_languages(
+                        &[lang_id],
+                        &available,
+                        None,
+                        NegotiationStrategy::Filtering,
+                    );
+                    for negotiated_lang_id in negotiated {
+                        let bundle =
+                            resources[*negotiated_lang_id].get(use_isolating);
+                        if let Some(result) =
+                            format_message(bundle, text_id, args)
+                        {
+                            return result;
+                        }
+                    }
+                }
+
+                for ((tr, bundles), locales) in
+                    translations.iter().zip(&chain.bundles).zip(locales)
+                {
+                    let bundles =
+                        bundles.map(|bundles| bundles.get(use_isolating));
+                    for negotiated_lang_id in locales {
+                        if let Some(result) = format_static_message(
+                            tr,
+                            bundles,
+                            negotiated_lang_id,
+                            text_id,
+                            args,
+                        ) {
+                            return result;
+                        }
+                    }
+                }
+            }
+
+            for (tr, bundles) in translations.iter().zip(&chain.bundles) {
+                if !chain.languages.contains(tr.fallback()) {
+                    if let Some(result) =
+                        resources.get(tr.fallback()).and_then(|bundles| {
+                            format_message(
+                                bundles.get(use_isolating),
+                                text_id,
+                                args,
+                            )
+                        })
+                    {
+                        return result;
+                    }
+                    if let Some(result) = format_static_message(
+                        tr,
+                        bundles.map(|bundles| bundles.get(use_isolating)),
+                        tr.fallback(),
+                        text_id,
+                        args,
+                    ) {
+                        return result;
+                    }
+                }
+            }
+
+            if let Some(fallback_language) = lazy_fallback_language {
+                if !chain.languages.contains(&fallback_language) {
+                    if let Some(result) =
+                        resources.get(&fallback_language).and_then(|bundles| {
+                            format_message(
+                                bundles.get(use_isolating),
+                                text_id,
+                                args,
+                            )
+                        })
+                    {
+                        return result;
+                    }
+                }
+            }
+
+            Err(ResolveError::MissingMessage)
+        })
+    }
+
+    /// Build the string to display for a translation error.
+    ///
+    /// Without an `on_error` handler, messages that Fluent fails to format
+    /// are displayed as formatted by Fluent despite the errors.
+    fn handle_error(
+        &self,
+        text_id: &str,
+        args: Option<&HashMap<Cow<'static, str>, FluentValue>>,
+        error: ResolveError,
+    ) -> String {
+        #[cfg(feature = "tracing")]
+        match &error {
+            ResolveError::MissingMessage => {
+                tracing::warn!(
+                    "Localization message \"{text_id}\" not found in any translation"
+                );
+            }
+            ResolveError::Format { error, .. } => {
+                tracing::error!(
+                    "Error formatting localization message \"{text_id}\": {error}"
+                );
+            }
+        }
+
+        match (self.on_error.get_value(), error) {
+            (Some(handler), error) => handler(&TranslationError {
+                text_id,
+                language: self.language.get_untracked(),
+                args,
+                kind: error.into(),
+            }),
+            (None, ResolveError::Format { formatted, .. }) => formatted,
+            (None, ResolveError::MissingMessage) => {
+                format!("Unknown localization {text_id}")
+            }
+        }
+    }
+
     /// Get the translation of a text identifier to the current language.
     ///
     /// ```rust,ignore
@@ -578,37 +1853,96 @@ impl I18n {
         tracing::instrument(level = "trace", skip_all)
     )]
     pub fn tr(&self, text_id: &str) -> String {
-        let language = self.language.get();
-
-        let found =
-            self.get_language_identifier(language).and_then(|lang_id| {
-                self.translations.with(|translations| {
-                    translations
-                        .iter()
-                        .find_map(|tr| tr.try_lookup(&lang_id, text_id))
-                })
-            });
+        let found = self.lookup(text_id, None);
 
-        #[cfg(feature = "tracing")]
-        match &found {
-            None => {
-                tracing::warn!(
-                    "Localization message \"{text_id}\" not found in any translation"
+        match found {
+            Ok(translated) => {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(
+                    "Localization message \"{}\" found in a translation. Translated to \"{}\".",
+                    text_id,
+                    translated
                 );
+
+                translated
             }
-            Some(translated) => {
+            Err(error) => self.handle_error(text_id, None, error),
+        }
+    }
+
+    /// Get the translation of a text identifier to the current language with arguments.
+    ///
+    /// ```rust,ignore
+    /// use leptos::prelude::expect_context;
+    /// use std::collections::HashMap;
+    /// use leptos_fluent::I18n;
+    ///
+    /// let i18n = expect_context::<I18n>();
+    /// let mut args = HashMap::new();
+    /// args.insert("name".into(), "John".into());
+    /// i18n.tr_with_args("hello-args", &args);
+    /// ```
+    ///
+    /// Calls to this function will not be checked by the `check_translations` option
+    /// in the `leptos_fluent!` macro. To avoid the warning for a specific identifier
+    /// or to use dynamic variables for translation data, use this function directly.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip_all)
+    )]
+    pub fn tr_with_args(
+        &self,
+        text_id: &str,
+        args: &std::collections::HashMap<Cow<'static, str>, FluentValue>,
+    ) -> String {
+        let found = self.lookup(text_id, Some(args));
+
+        match found {
+            Ok(translated) => {
+                #[cfg(feature = "tracing")]
                 tracing::trace!(
                     "Localization message \"{}\" found in a translation. Translated to \"{}\".",
                     text_id,
                     translated
                 );
+
+                translated
             }
+            Err(error) => self.handle_error(text_id, Some(args), error),
         }
+    }
 
-        found.unwrap_or_else(|| format!("Unknown localization {text_id}"))
+    /// Get the translation of a text identifier to the current language
+    /// if it exists.
+    ///
+    /// Returns `None` if the message is not found in the translations for
+    /// the current language or if Fluent raises errors resolving it. The
+    /// fallback languages are not used and the `on_error` handler is not
+    /// called.
+    ///
+    /// ```rust,ignore
+    /// use leptos::prelude::expect_context;
+    /// use leptos_fluent::I18n;
+    ///
+    /// if let Some(banner) = expect_context::<I18n>().try_tr("marketing-banner") {
+    ///     // ...
+    /// }
+    /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip_all)
+    )]
+    pub fn try_tr(&self, text_id: &str) -> Option<String> {
+        self.try_lookup(text_id, None)
     }
 
-    /// Get the translation of a text identifier to the current language with arguments.
+    /// Get the translation of a text identifier to the current language with
+    /// arguments if it exists.
+    ///
+    /// Returns `None` if the message is not found in the translations for
+    /// the current language or if Fluent raises errors resolving it. The
+    /// fallback languages are not used and the `on_error` handler is not
+    /// called.
     ///
     /// ```rust,ignore
     /// use leptos::prelude::expect_context;
@@ -617,53 +1951,162 @@ impl I18n {
     ///
     /// let i18n = expect_context::<I18n>();
     /// let mut args = HashMap::new();
-    /// args.insert("name".into(), "John".into());
-    /// i18n.tr_with_args("hello-args", &args);
+    /// args.insert("percent".into(), 20.into());
+    /// i18n.try_tr_with_args("discount", &args);
     /// ```
-    ///
-    /// Calls to this function will not be checked by the `check_translations` option
-    /// in the `leptos_fluent!` macro. To avoid the warning for a specific identifier
-    /// or to use dynamic variables for translation data, use this function directly.
     #[cfg_attr(
         feature = "tracing",
         tracing::instrument(level = "trace", skip_all)
     )]
-    pub fn tr_with_args(
+    pub fn try_tr_with_args(
         &self,
         text_id: &str,
         args: &std::collections::HashMap<Cow<'static, str>, FluentValue>,
-    ) -> String {
-        let language = self.language.get();
+    ) -> Option<String> {
+        self.try_lookup(text_id, Some(args))
+    }
+}
 
-        let found =
-            self.get_language_identifier(language).and_then(|lang_id| {
-                self.translations.with(|translations| {
-                    translations.iter().find_map(|tr| {
-                        tr.try_lookup_with_args(&lang_id, text_id, args)
-                    })
-                })
-            });
+/// Format a message or a `message.attribute` of a static loader.
+///
+/// Messages of loaders built by `leptos_fluent!` and [`static_loader!`] are
+/// formatted with the bundles of the context. Returns `None` if the message
+/// is not found and the errors raised by Fluent formatting it, if any.
+fn format_static_message(
+    tr: &StaticLoader,
+    bundles: Option<&'static StaticBundles>,
+    lang_id: &LanguageIdentifier,
+    text_id: &str,
+    args: Option<&HashMap<Cow<'static, str>, FluentValue>>,
+) -> Option<Result<String, ResolveError>> {
+    if let Some(bundles) = bundles {
+        return format_message(bundles.get(lang_id)?, text_id, args);
+    }
 
-        #[cfg(feature = "tracing")]
-        match &found {
-            None => {
-                tracing::warn!(
-                    "Localization message \"{text_id}\" not found in any translation"
-                );
-            }
-            Some(translated) => {
-                tracing::trace!(
-                    "Localization message \"{}\" found in a translation. Translated to \"{}\".",
-                    text_id,
-                    translated
-                );
-            }
-        }
+    // the errors of other loaders are private, so they're reported as
+    // missing messages
+    tr.lookup_single_language(lang_id, text_id, args)
+        .ok()
+        .map(Ok)
+}
+
+/// Format a message or a `message.attribute` of a Fluent bundle.
+///
+/// Returns `None` if the message is not found and the errors raised by
+/// Fluent formatting it, if any.
+fn format_message<R: Borrow<FluentResource>, M: MemoizerKind>(
+    bundle: &FluentBundle<R, M>,
+    text_id: &str,
+    args: Option<&HashMap<Cow<'static, str>, FluentValue>>,
+) -> Option<Result<String, ResolveError>> {
+    let (message_id, attribute_id) = match text_id.split_once('.') {
+        Some((message_id, attribute_id)) => (message_id, Some(attribute_id)),
+        None => (text_id, None),
+    };
+    let message = bundle.get_message(message_id)?;
+    let pattern = match attribute_id {
+        Some(attribute_id) => message.get_attribute(attribute_id)?.value(),
+        None => message.value()?,
+    };
+    let fluent_args = args.map(|args| {
+        args.iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect::<FluentArgs>()
+    });
+
+    let mut errors = vec![];
+    let formatted =
+        bundle.format_pattern(pattern, fluent_args.as_ref(), &mut errors);
+    match errors.into_iter().next() {
+        Some(error) => Some(Err(ResolveError::Format {
+            error: error.to_string(),
+            formatted: formatted.into_owned(),
+        })),
+        None => Some(Ok(formatted.into_owned())),
+    }
+}
+
+/// Contexts provided by `leptos_fluent!` with the `namespace` parameter.
+///
+/// Namespaced contexts don't replace the [`I18n`] context of the
+/// application, so libraries of components can ship their own translations
+/// without colliding with the message identifiers of the host application.
+#[derive(Clone, Debug, Default)]
+pub struct I18nNamespaces(Vec<(&'static str, I18n)>);
+
+impl I18nNamespaces {
+    /// Namespaced contexts available in the current reactive owner.
+    pub fn current() -> Self {
+        leptos::prelude::use_context::<Self>().unwrap_or_default()
+    }
+
+    /// Add a namespaced context, replacing the context of the same
+    /// namespace if any.
+    pub fn with(mut self, namespace: &'static str, i18n: I18n) -> Self {
+        self.0.retain(|(ns, _)| *ns != namespace);
+        self.0.push((namespace, i18n));
+        self
+    }
 
-        found.unwrap_or_else(|| format!("Unknown localization {text_id}"))
+    /// Get the context of a namespace.
+    pub fn get(&self, namespace: &str) -> Option<I18n> {
+        self.0
+            .iter()
+            .find(|(ns, _)| *ns == namespace)
+            .map(|(_, i18n)| *i18n)
     }
 }
 
+/// Get the context provided by `leptos_fluent!` with a `namespace`.
+pub fn use_i18n_namespace(namespace: &str) -> Option<I18n> {
+    I18nNamespaces::current().get(namespace)
+}
+
+/// Get the context provided by `leptos_fluent!` with a `namespace`.
+///
+/// Used by the translation macros with the `ns` option.
+///
+/// # Panics
+///
+/// If the context of the namespace has not been provided.
+pub fn expect_i18n_namespace(namespace: &str) -> I18n {
+    use_i18n_namespace(namespace).unwrap_or_else(|| {
+        panic!(
+            concat!(
+                "I18n context for namespace \"{}\" not found. Provide it",
+                " calling `leptos_fluent!` with the parameter",
+                " `namespace: \"{}\"`."
+            ),
+            namespace, namespace,
+        )
+    })
+}
+
+/// Localize an absolute path of the application with the current language
+/// as its first segment.
+///
+/// Used to build links with the URL path strategy (`url_path: true`) that
+/// preserve the language of the user. The [route slugs](routes) of the path
+/// are translated to the language. Relative paths and URLs of other origins
+/// are returned unchanged. Reads the language, so it's reactive inside
+/// closures.
+///
+/// ```rust,ignore
+/// use leptos::prelude::*;
+/// use leptos_fluent::localized_href;
+///
+/// // `/es/precios` when the current language is Spanish and
+/// // `route-pricing = precios` is defined
+/// view! { <a href=move || localized_href("/pricing")>"Pricing"</a> }
+/// ```
+///
+/// See also [`components::LocalizedA`].
+#[cfg(feature = "router")]
+pub fn localized_href(href: &str) -> String {
+    let i18n = leptos::prelude::expect_context::<I18n>();
+    url::path::localize_href(&i18n, Some(i18n.language.get()), href)
+}
+
 // get language
 #[cfg(feature = "nightly")]
 impl FnOnce<()> for I18n {
@@ -751,8 +2194,48 @@ impl Fn<(&'static Language,)> for I18n {
 /// match against the translations files. To avoid the warning for a specific
 /// identifier or to use dynamic variables for translation data, use `i18n.tr`
 /// or `i18n.tr_with_args` methods directly.
+///
+/// The Unicode isolation marks around placeables can be enabled or disabled
+/// for a call with the `use_isolating` option, overriding the
+/// `use_isolating` parameter of `leptos_fluent!`:
+///
+/// ```rust,ignore
+/// use leptos_fluent::tr;
+///
+/// tr!(use_isolating = false, "hello-world", { "name" => "John" });
+/// ```
+///
+/// The `ns` option translates with the context provided by `leptos_fluent!`
+/// with the same `namespace` parameter:
+///
+/// ```rust,ignore
+/// use leptos_fluent::tr;
+///
+/// tr!(ns = "widget", "widget-title");
+/// ```
 #[macro_export]
 macro_rules! tr {
+    (ns = $ns:literal, use_isolating = $use_isolating:expr, $($rest:tt)+) => {{
+        let i18n = $crate::expect_i18n_namespace($ns)
+            .with_use_isolating($use_isolating);
+        $crate::tr!(i18n, $($rest)+)
+    }};
+    (ns = $ns:literal, $($rest:tt)+) => {{
+        let i18n = $crate::expect_i18n_namespace($ns);
+        $crate::tr!(i18n, $($rest)+)
+    }};
+    (use_isolating = $use_isolating:expr, ns = $ns:literal, $($rest:tt)+) => {
+        $crate::tr!(ns = $ns, use_isolating = $use_isolating, $($rest)+)
+    };
+    (use_isolating = $use_isolating:expr, $i18n:ident, $($rest:tt)+) => {{
+        let i18n = $i18n.with_use_isolating($use_isolating);
+        $crate::tr!(i18n, $($rest)+)
+    }};
+    (use_isolating = $use_isolating:expr, $($rest:tt)+) => {{
+        let i18n = ::leptos::prelude::expect_context::<$crate::I18n>()
+            .with_use_isolating($use_isolating);
+        $crate::tr!(i18n, $($rest)+)
+    }};
     ($text_id:literal$(,)?) => {::leptos::prelude::expect_context::<$crate::I18n>().tr($text_id)};
     (
         $text_id:literal,
@@ -1022,6 +2505,16 @@ macro_rules! tr {
 /// [Leptos's `Signal`]: https://docs.rs/reactive_graph/0.1.0/reactive_graph/wrappers/read/struct.Signal.html
 #[macro_export]
 macro_rules! move_tr {
+    (ns = $ns:literal, $($rest:tt)+) => {
+        ::leptos::prelude::Signal::derive(move || {
+            $crate::tr!(ns = $ns, $($rest)+)
+        })
+    };
+    (use_isolating = $use_isolating:expr, $($rest:tt)+) => {
+        ::leptos::prelude::Signal::derive(move || {
+            $crate::tr!(use_isolating = $use_isolating, $($rest)+)
+        })
+    };
     ($text_id:literal$(,)?) => {
         ::leptos::prelude::Signal::derive(move || $crate::tr!($text_id))
     };
@@ -1251,6 +2744,195 @@ macro_rules! move_tr {
     };
 }
 
+/// Translate a text identifier to the current language if the message exists.
+///
+/// Returns an `Option<String>` that is `None` when the message is not found
+/// in the translations for the current language or when Fluent raises errors
+/// resolving it. The fallback languages are not used and the `on_error`
+/// handler is not called.
+///
+/// ```rust,ignore
+/// use leptos_fluent::try_tr;
+///
+/// try_tr!("marketing-banner")
+/// try_tr!("discount", { "percent" => 20 });
+/// ```
+///
+/// When using `check_translations` option in the `leptos_fluent!` macro,
+/// the arguments of `try_tr!` are checked against the translations files,
+/// but messages are not required to be defined for all languages.
+#[macro_export]
+macro_rules! try_tr {
+    (ns = $ns:literal, use_isolating = $use_isolating:expr, $($rest:tt)+) => {{
+        let i18n = $crate::expect_i18n_namespace($ns)
+            .with_use_isolating($use_isolating);
+        $crate::try_tr!(i18n, $($rest)+)
+    }};
+    (ns = $ns:literal, $($rest:tt)+) => {{
+        let i18n = $crate::expect_i18n_namespace($ns);
+        $crate::try_tr!(i18n, $($rest)+)
+    }};
+    (use_isolating = $use_isolating:expr, ns = $ns:literal, $($rest:tt)+) => {
+        $crate::try_tr!(ns = $ns, use_isolating = $use_isolating, $($rest)+)
+    };
+    (use_isolating = $use_isolating:expr, $i18n:ident, $($rest:tt)+) => {{
+        let i18n = $i18n.with_use_isolating($use_isolating);
+        $crate::try_tr!(i18n, $($rest)+)
+    }};
+    (use_isolating = $use_isolating:expr, $($rest:tt)+) => {{
+        let i18n = ::leptos::prelude::expect_context::<$crate::I18n>()
+            .with_use_isolating($use_isolating);
+        $crate::try_tr!(i18n, $($rest)+)
+    }};
+    ($text_id:literal$(,)?) => {::leptos::prelude::expect_context::<$crate::I18n>().try_tr($text_id)};
+    (
+        $text_id:literal,
+        $( #[$args_cfgs:meta] )*
+        {$($key:literal => $value:expr),*$(,)?}
+        $(,)?
+    ) => {{
+        ::leptos::prelude::expect_context::<$crate::I18n>().try_tr_with_args(
+            $text_id,
+            $( #[$args_cfgs] )*
+            &{
+                let mut map = ::std::collections::HashMap::new();
+                $(
+                    map.insert($key.into(), $value.into());
+                )*
+                map
+            }
+        )
+    }};
+    ($i18n:ident, $text_id:literal$(,)?) => {$i18n.try_tr($text_id)};
+    (
+        $i18n:ident,
+        $text_id:literal,
+        $( #[$args_cfgs:meta] )*
+        {$($key:literal => $value:expr),*$(,)?}
+        $(,)?
+    ) => {{
+        $i18n.try_tr_with_args(
+            $text_id,
+            $( #[$args_cfgs] )*
+            &{
+                let mut map = ::std::collections::HashMap::new();
+                $(
+                    map.insert($key.into(), $value.into());
+                )*
+                map
+            }
+        )
+    }};
+    (
+        $( #[$id_cfgs:meta] )*
+        $text_id:expr$(,)?
+    ) => {
+        compile_error!(format!("Expected a string literal, got an expression '{}'", stringify!($text_id)))
+    };
+    (
+        $( #[$id_cfgs:meta] )*
+        $text_id:expr,
+        $( #[$args_cfgs:meta] )*
+        {$($key:literal => $value:expr),*$(,)?}$(,)?
+    ) => {
+        compile_error!(format!("Expected a string literal, got an expression '{}'", stringify!($text_id)))
+    };
+}
+
+/// [Leptos's `Signal`] that translates a text identifier to the current language
+/// if the message exists.
+///
+/// ```rust,ignore
+/// use leptos_fluent::try_move_tr;
+///
+/// let banner = try_move_tr!("marketing-banner");
+/// view! {
+///     <Show when=move || banner.get().is_some()>
+///         <p>{move || banner.get()}</p>
+///     </Show>
+/// }
+/// ```
+///
+/// The same as:
+///
+/// ```rust,ignore
+/// Signal::derive(move || try_tr!("marketing-banner"));
+/// ```
+///
+/// [Leptos's `Signal`]: https://docs.rs/reactive_graph/0.1.0/reactive_graph/wrappers/read/struct.Signal.html
+#[macro_export]
+macro_rules! try_move_tr {
+    (ns = $ns:literal, $($rest:tt)+) => {
+        ::leptos::prelude::Signal::derive(move || {
+            $crate::try_tr!(ns = $ns, $($rest)+)
+        })
+    };
+    (use_isolating = $use_isolating:expr, $($rest:tt)+) => {
+        ::leptos::prelude::Signal::derive(move || {
+            $crate::try_tr!(use_isolating = $use_isolating, $($rest)+)
+        })
+    };
+    ($text_id:literal$(,)?) => {
+        ::leptos::prelude::Signal::derive(move || $crate::try_tr!($text_id))
+    };
+    (
+        $text_id:literal,
+        $( #[$args_cfgs:meta] )*
+        {$($key:literal => $value:expr),*$(,)?}
+        $(,)?
+    ) => {
+        ::leptos::prelude::Signal::derive(move || $crate::try_tr!(
+            $text_id,
+            $( #[$args_cfgs] )*
+            {
+                $(
+                    $key => $value,
+                )*
+            }
+        ))
+    };
+    ($i18n:ident, $text_id:literal$(,)?) => {
+        ::leptos::prelude::Signal::derive(move || $crate::try_tr!($i18n, $text_id))
+    };
+    (
+        $i18n:ident,
+        $text_id:literal,
+        $( #[$args_cfgs:meta] )*
+        {$($key:literal => $value:expr),*$(,)?}
+        $(,)?
+    ) => {
+        ::leptos::prelude::Signal::derive(move || $crate::try_tr!(
+            $i18n,
+            $text_id,
+            $( #[$args_cfgs] )*
+            {
+                $(
+                    $key => $value,
+                )*
+            }
+        ))
+    };
+    (
+        $( #[$id_cfgs:meta] )*
+        $text_id:expr$(,)?
+    ) => {
+        compile_error!(format!("Expected a string literal, got an expression '{}'", stringify!($text_id)))
+    };
+    (
+        $( #[$id_cfgs:meta] )*
+        $text_id:expr,
+        $( #[$args_cfgs:meta] )*
+        {$($key:literal => $value:expr),*$(,)?}$(,)?
+    ) => {
+        compile_error!(format!("Expected a string literal, got an expression '{}'", stringify!($text_id)))
+    };
+}
+
+/// Search a language by its code between `languages`.
+///
+/// The language is negotiated with the strategy of the `I18n` context if
+/// there is one in the current reactive owner, otherwise with the default
+/// [`negotiation::NegotiationStrategy::Filtering`] strategy.
 #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
 #[doc(hidden)]
 pub fn language_from_str_between_languages(
@@ -1268,56 +2950,22 @@ pub fn language_from_str_between_languages(
             .join(", ")
     );
 
-    let target_lang = LanguageIdentifier::from_str(code).ok()?;
-    let languages_identifiers: Vec<(&'static Language, LanguageIdentifier)> =
-        languages
-            .iter()
-            .filter_map(|&lang| {
-                LanguageIdentifier::from_str(lang.id)
-                    .ok()
-                    .map(|id| (lang, id))
-            })
-            .collect();
-
-    // Exact search
-    if let Some(&(lang, _)) = languages_identifiers
-        .iter()
-        .find(|(_, id)| id.matches(&target_lang, false, false))
-    {
-        #[cfg(feature = "tracing")]
-        tracing::trace!(
-            "Language with code \"{}\" found with exact search: \"{}\"",
-            code,
-            lang.id
-        );
-        return Some(lang);
-    }
-
-    // Fuzzy search
-    let lazy_target_lang = LanguageIdentifier::from_raw_parts_unchecked(
-        target_lang.language,
-        None,
-        None,
-        None,
-    );
+    let strategy = leptos::prelude::use_context::<I18n>()
+        .map(|i18n| i18n.negotiation_strategy())
+        .unwrap_or_default();
+    let language = negotiation::negotiate_language(code, languages, strategy);
 
-    if let Some(&(lang, _)) = languages_identifiers
-        .iter()
-        .find(|(_, id)| id.matches(&lazy_target_lang, true, true))
-    {
-        #[cfg(feature = "tracing")]
-        tracing::trace!(
-            "Language with code \"{}\" found with fuzzy search: \"{}\"",
+    #[cfg(feature = "tracing")]
+    match language {
+        Some(lang) => tracing::trace!(
+            "Language with code \"{}\" found: \"{}\"",
             code,
             lang.id
-        );
-        return Some(lang);
+        ),
+        None => tracing::trace!("Language with code \"{}\" not found", code),
     }
 
-    #[cfg(feature = "tracing")]
-    tracing::trace!("Language with code \"{}\" not found", code);
-
-    None
+    language
 }
 
 // Used by `leptos_fluent!` macro
@@ -1330,6 +2978,17 @@ pub fn l(
     language_from_str_between_languages(code, languages)
 }
 
+// Used by `leptos_fluent!` macro
+#[doc(hidden)]
+#[inline(always)]
+pub fn l_with_strategy(
+    code: &str,
+    languages: &'static [&Language],
+    strategy: negotiation::NegotiationStrategy,
+) -> Option<&'static Language> {
+    negotiation::negotiate_language(code, languages, strategy)
+}
+
 /// Parameters passed to `leptos_fluent!` macro at creation of `i18n` context
 #[derive(Clone, Debug)]
 #[doc(hidden)]
@@ -1344,6 +3003,8 @@ pub struct LeptosFluentMeta {
     pub provide_meta_context: bool,
     pub sync_html_tag_lang: bool,
     pub sync_html_tag_dir: bool,
+    pub sync_hreflang_links: bool,
+    pub hreflang_origin: Option<&'static str>,
     pub url_param: &'static str,
     pub initial_language_from_url_param: bool,
     pub initial_language_from_url_param_to_local_storage: bool,
@@ -1387,6 +3048,7 @@ pub struct LeptosFluentMeta {
     pub initial_language_from_url_path_to_local_storage: bool,
     pub initial_language_from_url_path_to_session_storage: bool,
     pub initial_language_from_url_path_to_server_function: bool, // *
+    pub set_language_to_url_path: bool,
     #[cfg(feature = "system")]
     pub initial_language_from_system: bool,
     #[cfg(feature = "system")]
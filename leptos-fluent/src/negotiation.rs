@@ -0,0 +1,178 @@
+//! Language negotiation.
+//!
+//! The languages requested by the initial language sources (URL, cookies,
+//! storages, navigator, system, `Accept-Language` header...) are negotiated
+//! against the languages of the application following [RFC 4647], using
+//! the likely subtags of the CLDR to take scripts and regions into account.
+//! For example, `zh-TW` resolves to `zh-Hant`, `sr-Latn-RS` to `sr-Latn`
+//! and `pt` to `pt-BR` when `pt-BR` and `pt-PT` are available.
+//!
+//! The strategy is chosen with the `negotiation_strategy` parameter of
+//! [`leptos_fluent!`].
+//!
+//! [RFC 4647]: https://www.rfc-editor.org/rfc/rfc4647
+//! [`leptos_fluent!`]: crate::leptos_fluent
+
+use crate::{pseudo, Language};
+use core::str::FromStr;
+use fluent_templates::LanguageIdentifier;
+
+/// Strategy to negotiate a requested language against the languages of
+/// the application.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NegotiationStrategy {
+    /// Extended filtering ([RFC 4647 section 3.3.2]).
+    ///
+    /// Matches the requested language against the languages of the
+    /// application treated as ranges, then against the requested language
+    /// maximized with its likely subtags, and finally against other regions
+    /// of the same language. `en-AU` resolves to `en-US` if there is no
+    /// `en` nor `en-AU` language.
+    ///
+    /// [RFC 4647 section 3.3.2]: https://www.rfc-editor.org/rfc/rfc4647#section-3.3.2
+    #[default]
+    Filtering,
+    /// Lookup ([RFC 4647 section 3.4]).
+    ///
+    /// Progressively truncates the requested language and its maximized
+    /// form until a language of the application matches exactly, so a
+    /// language for another region is never selected. `en-AU` resolves to
+    /// `en` but not to `en-US`.
+    ///
+    /// [RFC 4647 section 3.4]: https://www.rfc-editor.org/rfc/rfc4647#section-3.4
+    Lookup,
+}
+
+/// Negotiate a requested language between the languages of the application.
+///
+/// Pseudolocales are only selected when they're requested exactly.
+///
+/// ```rust
+/// use leptos_fluent::negotiation::{negotiate_language, NegotiationStrategy};
+/// use leptos_fluent::{Language, WritingDirection};
+///
+/// static HANS: Language =
+///     Language::new("zh-Hans", "简体中文", &WritingDirection::Ltr)
+///         .with_script("Hans");
+/// static HANT: Language =
+///     Language::new("zh-Hant", "繁體中文", &WritingDirection::Ltr)
+///         .with_script("Hant");
+/// static LANGUAGES: &[&Language] = &[&HANS, &HANT];
+///
+/// let negotiated = negotiate_language("zh-TW", LANGUAGES, NegotiationStrategy::Filtering);
+/// assert_eq!(negotiated.map(|lang| lang.id), Some("zh-Hant"));
+/// let negotiated = negotiate_language("zh-CN", LANGUAGES, NegotiationStrategy::Lookup);
+/// assert_eq!(negotiated.map(|lang| lang.id), Some("zh-Hans"));
+/// ```
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip_all))]
+pub fn negotiate_language(
+    code: &str,
+    languages: &'static [&'static Language],
+    strategy: NegotiationStrategy,
+) -> Option<&'static Language> {
+    #[cfg(feature = "tracing")]
+    tracing::trace!(
+        "Negotiating language with code {:?} using {:?} strategy. Available languages: {}",
+        code,
+        strategy,
+        languages
+            .iter()
+            .map(|lang| lang.id)
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let requested = LanguageIdentifier::from_str(code).ok()?;
+    let available = languages
+        .iter()
+        .filter_map(|&lang| {
+            let id = lang
+                .identifier
+                .clone()
+                .or_else(|| LanguageIdentifier::from_str(lang.id).ok())?;
+            (id == requested || !pseudo::is_pseudolocale(&id))
+                .then_some((lang, id))
+        })
+        .collect::<Vec<_>>();
+
+    let negotiated = match strategy {
+        NegotiationStrategy::Filtering => {
+            let available_ids =
+                available.iter().map(|(_, id)| id).collect::<Vec<_>>();
+            fluent_langneg::negotiate_languages(
+                &[&requested],
+                &available_ids,
+                None,
+                fluent_langneg::NegotiationStrategy::Filtering,
+            )
+            .first()
+            .and_then(|negotiated| {
+                available.iter().find(|(_, id)| id == **negotiated)
+            })
+            .map(|(lang, _)| *lang)
+        }
+        NegotiationStrategy::Lookup => lookup_candidates(&requested)
+            .into_iter()
+            .find_map(|candidate| {
+                available
+                    .iter()
+                    .find(|(_, id)| *id == candidate)
+                    .map(|(lang, _)| *lang)
+            }),
+    };
+
+    #[cfg(feature = "tracing")]
+    tracing::trace!(
+        "Language with code {:?} negotiated to {:?}",
+        code,
+        negotiated.map(|lang| lang.id)
+    );
+
+    negotiated
+}
+
+/// Language identifiers to match exactly for a requested language, in
+/// order of preference.
+///
+/// The truncations of the requested language are followed by those of its
+/// maximized form. The likely script of the maximized form is also omitted
+/// to match languages defined with a region, like `pt-BR` for `pt`.
+fn lookup_candidates(
+    requested: &LanguageIdentifier,
+) -> Vec<LanguageIdentifier> {
+    let mut candidates = truncations(requested);
+
+    let mut maximized = requested.clone();
+    maximized.clear_variants();
+    if maximized.maximize() {
+        let mut without_script = maximized.clone();
+        without_script.script = None;
+        for candidate in truncations(&maximized)
+            .into_iter()
+            .chain(truncations(&without_script))
+        {
+            if !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        }
+    }
+
+    candidates
+}
+
+/// Truncations of a language identifier removing its subtags from the end.
+fn truncations(lang_id: &LanguageIdentifier) -> Vec<LanguageIdentifier> {
+    let mut truncations = vec![lang_id.clone()];
+    let mut truncated = lang_id.clone();
+    if truncated.variants().next().is_some() {
+        truncated.clear_variants();
+        truncations.push(truncated.clone());
+    }
+    if truncated.region.take().is_some() {
+        truncations.push(truncated.clone());
+    }
+    if truncated.script.take().is_some() {
+        truncations.push(truncated);
+    }
+    truncations
+}
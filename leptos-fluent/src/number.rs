@@ -0,0 +1,647 @@
+//! Locale-aware formatting of numbers.
+//!
+//! Enabled with the `number` feature. The bundles built by [`leptos_fluent!`]
+//! and [`static_loader!`] and the resources added with
+//! [`I18n::add_resource`] get a `NUMBER()` function and a formatter that
+//! localize numbers using the data of the [CLDR], with [ICU4X] on the server
+//! or desktop and [`Intl.NumberFormat`] in the browser.
+//!
+//! ```ftl
+//! total = Total: { NUMBER($amount, style: "currency", currency: "EUR") }
+//! progress = { NUMBER($ratio, style: "percent") } completed
+//! distance = { NUMBER($km, style: "unit", unit: "kilometer") }
+//! ```
+//!
+//! Besides the options supported by [`FluentNumberOptions`], the `unit`
+//! style accepts the `unit` and `unitDisplay` (`short`, `narrow` or
+//! `long`) options. On the server only the [units sanctioned by ECMA-402]
+//! of length, area, volume, mass and duration are localized, the rest are
+//! rendered as the localized number followed by the unit identifier.
+//!
+//! Loaders built by `fluent_templates::static_loader!` format their
+//! messages with their own bundles, so call [`add_number_function`] from
+//! their `customise` closure when passing them to the `translations`
+//! parameter of [`leptos_fluent!`].
+//!
+//! [`leptos_fluent!`]: crate::leptos_fluent
+//! [`static_loader!`]: crate::static_loader
+//! [`I18n::add_resource`]: crate::I18n::add_resource
+//! [CLDR]: https://cldr.unicode.org
+//! [ICU4X]: https://icu4x.unicode.org
+//! [`Intl.NumberFormat`]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat
+//! [units sanctioned by ECMA-402]: https://tc39.es/ecma402/#table-sanctioned-single-unit-identifiers
+
+use fluent_bundle::{
+    bundle::FluentBundle,
+    memoizer::MemoizerKind,
+    types::{
+        FluentNumber, FluentNumberCurrencyDisplayStyle, FluentNumberOptions,
+        FluentNumberStyle, FluentType,
+    },
+    FluentArgs, FluentValue,
+};
+use fluent_templates::LanguageIdentifier;
+use intl_memoizer::Memoizable;
+use std::borrow::Cow;
+
+/// Install the locale-aware `NUMBER()` function and number formatter
+/// into a Fluent bundle.
+///
+/// ```rust,ignore
+/// static_loader! {
+///     static TRANSLATIONS = {
+///         locales: "./locales",
+///         fallback_language: "en",
+///         customise: |bundle| leptos_fluent::number::add_number_function(bundle),
+///     };
+/// }
+/// ```
+pub fn add_number_function<R, M: MemoizerKind>(
+    bundle: &mut FluentBundle<R, M>,
+) {
+    // a `NUMBER()` function already installed in the bundle is preserved
+    _ = bundle.add_function("NUMBER", number);
+    bundle.set_formatter(Some(format_value::<M>));
+}
+
+fn number<'a>(
+    positional: &[FluentValue<'a>],
+    named: &FluentArgs,
+) -> FluentValue<'a> {
+    let mut number = match positional.first() {
+        Some(FluentValue::Number(number)) => number.clone(),
+        Some(FluentValue::String(string)) => {
+            match string.parse::<FluentNumber>() {
+                Ok(number) => number,
+                Err(_) => return FluentValue::Error,
+            }
+        }
+        _ => return FluentValue::Error,
+    };
+    number.options.merge(named);
+
+    match (named.get("style"), named.get("unit")) {
+        (Some(FluentValue::String(style)), Some(FluentValue::String(unit)))
+            if style == "unit" =>
+        {
+            let display = match named.get("unitDisplay") {
+                Some(FluentValue::String(display)) => {
+                    UnitDisplay::from(display.as_ref())
+                }
+                _ => UnitDisplay::default(),
+            };
+            FluentValue::Custom(Box::new(UnitNumber {
+                number,
+                unit: unit.to_string(),
+                display,
+            }))
+        }
+        _ => FluentValue::Number(number),
+    }
+}
+
+fn format_value<M: MemoizerKind>(
+    value: &FluentValue,
+    intls: &M,
+) -> Option<String> {
+    match value {
+        FluentValue::Number(number) => {
+            format_number(number, Style::from(&number.options), intls)
+        }
+        _ => None,
+    }
+}
+
+fn format_number<M: MemoizerKind>(
+    number: &FluentNumber,
+    style: Style,
+    intls: &M,
+) -> Option<String> {
+    let args = NumberFormatterArgs {
+        style,
+        use_grouping: number.options.use_grouping,
+    };
+    intls
+        .with_try_get_threadsafe::<NumberFormatter, _, _>(args, |formatter| {
+            formatter.format(number)
+        })
+        .ok()
+        .flatten()
+}
+
+/// Width of the units formatted with the `unit` style.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+enum UnitDisplay {
+    #[default]
+    Short,
+    Narrow,
+    Long,
+}
+
+impl From<&str> for UnitDisplay {
+    fn from(input: &str) -> Self {
+        match input {
+            "narrow" => Self::Narrow,
+            "long" => Self::Long,
+            _ => Self::default(),
+        }
+    }
+}
+
+impl UnitDisplay {
+    #[cfg(target_arch = "wasm32")]
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Short => "short",
+            Self::Narrow => "narrow",
+            Self::Long => "long",
+        }
+    }
+}
+
+/// Number returned by `NUMBER()` for the `unit` style.
+#[derive(Clone, Debug, PartialEq)]
+struct UnitNumber {
+    number: FluentNumber,
+    unit: String,
+    display: UnitDisplay,
+}
+
+impl UnitNumber {
+    fn format<M: MemoizerKind>(&self, intls: &M) -> Cow<'static, str> {
+        let style = Style::Unit(self.unit.clone(), self.display);
+        match format_number(&self.number, style, intls) {
+            Some(formatted) => formatted.into(),
+            None => format!("{} {}", self.number.as_string(), self.unit).into(),
+        }
+    }
+}
+
+impl FluentType for UnitNumber {
+    fn duplicate(&self) -> Box<dyn FluentType + Send> {
+        Box::new(self.clone())
+    }
+
+    fn as_string(
+        &self,
+        intls: &intl_memoizer::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        self.format(intls)
+    }
+
+    fn as_string_threadsafe(
+        &self,
+        intls: &intl_memoizer::concurrent::IntlLangMemoizer,
+    ) -> Cow<'static, str> {
+        self.format(intls)
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Style {
+    Decimal,
+    Percent,
+    Currency(String, FluentNumberCurrencyDisplayStyle),
+    Unit(String, UnitDisplay),
+}
+
+impl From<&FluentNumberOptions> for Style {
+    fn from(options: &FluentNumberOptions) -> Self {
+        match (options.style, &options.currency) {
+            (FluentNumberStyle::Percent, _) => Self::Percent,
+            (FluentNumberStyle::Currency, Some(currency)) => {
+                Self::Currency(currency.clone(), options.currency_display)
+            }
+            _ => Self::Decimal,
+        }
+    }
+}
+
+impl Style {
+    /// Default minimum and maximum fraction digits of the style.
+    fn fraction_digits(&self) -> (usize, usize) {
+        match self {
+            Self::Percent => (0, 0),
+            Self::Currency(..) => (2, 2),
+            Self::Decimal | Self::Unit(..) => (0, 3),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct NumberFormatterArgs {
+    style: Style,
+    use_grouping: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+enum IcuFormatter {
+    Decimal(icu_decimal::DecimalFormatter),
+    Percent(
+        icu_experimental::dimension::percent::formatter::PercentFormatter<
+            icu_decimal::DecimalFormatter,
+        >,
+    ),
+    Currency(
+        icu_experimental::dimension::currency::formatter::CurrencyFormatter<
+            icu_decimal::DecimalFormatter,
+        >,
+    ),
+    Unit(UnitFormatter),
+    /// Units without localized names are rendered after the number.
+    UnitIdentifier(icu_decimal::DecimalFormatter, String),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+enum UnitFormatter {
+    Area(
+        icu_experimental::dimension::units::categorized_formatter::CategorizedFormatter<
+            icu_experimental::measure::category::Area,
+        >,
+    ),
+    Duration(
+        icu_experimental::dimension::units::categorized_formatter::CategorizedFormatter<
+            icu_experimental::measure::category::Duration,
+        >,
+    ),
+    Length(
+        icu_experimental::dimension::units::categorized_formatter::CategorizedFormatter<
+            icu_experimental::measure::category::Length,
+        >,
+    ),
+    Mass(
+        icu_experimental::dimension::units::categorized_formatter::CategorizedFormatter<
+            icu_experimental::measure::category::Mass,
+        >,
+    ),
+    Volume(
+        icu_experimental::dimension::units::categorized_formatter::CategorizedFormatter<
+            icu_experimental::measure::category::Volume,
+        >,
+    ),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl UnitFormatter {
+    fn try_new(
+        locale: &icu_locale_core::Locale,
+        unit: &str,
+        display: UnitDisplay,
+    ) -> Option<Self> {
+        use icu_experimental::dimension::units::{
+            categorized_formatter::CategorizedFormatter,
+            options::{UnitsFormatterOptions, Width},
+        };
+        use icu_experimental::measure::category::{
+            Area, Duration, Length, Mass, Volume,
+        };
+
+        let mut options = UnitsFormatterOptions::default();
+        options.width = match display {
+            UnitDisplay::Short => Width::Short,
+            UnitDisplay::Narrow => Width::Narrow,
+            UnitDisplay::Long => Width::Long,
+        };
+
+        macro_rules! categorized_formatter {
+            ($variant:ident, $base_unit:expr, $cldr_id:expr) => {{
+                let mut categorized_unit = $base_unit;
+                categorized_unit.unit.id = Some($cldr_id);
+                // the outlier data contains the names of all the units
+                CategorizedFormatter::try_new_outlier(
+                    locale.into(),
+                    categorized_unit,
+                    options,
+                )
+                .ok()
+                .map(Self::$variant)
+            }};
+        }
+
+        let cldr_id = SANCTIONED_UNITS
+            .iter()
+            .find(|sanctioned_unit| **sanctioned_unit == unit)?;
+        match *cldr_id {
+            "acre" | "hectare" => {
+                categorized_formatter!(Area, Area::square_meter(), cldr_id)
+            }
+            "day" | "hour" | "microsecond" | "millisecond" | "minute"
+            | "month" | "nanosecond" | "second" | "week" | "year" => {
+                categorized_formatter!(Duration, Duration::second(), cldr_id)
+            }
+            "centimeter" | "foot" | "inch" | "kilometer" | "meter" | "mile"
+            | "mile-scandinavian" | "millimeter" | "yard" => {
+                categorized_formatter!(Length, Length::meter(), cldr_id)
+            }
+            "gram" | "kilogram" | "ounce" | "pound" | "stone" => {
+                categorized_formatter!(Mass, Mass::gram(), cldr_id)
+            }
+            "fluid-ounce" | "gallon" | "liter" | "milliliter" => {
+                categorized_formatter!(Volume, Volume::liter(), cldr_id)
+            }
+            _ => None,
+        }
+    }
+
+    fn format(&self, value: &fixed_decimal::Decimal) -> String {
+        match self {
+            Self::Area(formatter) => {
+                formatter.format_fixed_decimal(value).to_string()
+            }
+            Self::Duration(formatter) => {
+                formatter.format_fixed_decimal(value).to_string()
+            }
+            Self::Length(formatter) => {
+                formatter.format_fixed_decimal(value).to_string()
+            }
+            Self::Mass(formatter) => {
+                formatter.format_fixed_decimal(value).to_string()
+            }
+            Self::Volume(formatter) => {
+                formatter.format_fixed_decimal(value).to_string()
+            }
+        }
+    }
+}
+
+/// Units of length, area, volume, mass and duration sanctioned by ECMA-402.
+#[cfg(not(target_arch = "wasm32"))]
+const SANCTIONED_UNITS: &[&str] = &[
+    "acre",
+    "centimeter",
+    "day",
+    "fluid-ounce",
+    "foot",
+    "gallon",
+    "gram",
+    "hectare",
+    "hour",
+    "inch",
+    "kilogram",
+    "kilometer",
+    "liter",
+    "meter",
+    "microsecond",
+    "mile",
+    "mile-scandinavian",
+    "milliliter",
+    "millimeter",
+    "millisecond",
+    "minute",
+    "month",
+    "nanosecond",
+    "ounce",
+    "pound",
+    "second",
+    "stone",
+    "week",
+    "yard",
+    "year",
+];
+
+/// Number formatter memoized by language and options in the bundles.
+struct NumberFormatter {
+    args: NumberFormatterArgs,
+    #[cfg(not(target_arch = "wasm32"))]
+    formatter: IcuFormatter,
+    /// `Intl.NumberFormat` is not thread safe, so it's built on formatting.
+    #[cfg(target_arch = "wasm32")]
+    locale: String,
+}
+
+impl Memoizable for NumberFormatter {
+    type Args = NumberFormatterArgs;
+    type Error = ();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn construct(
+        lang: LanguageIdentifier,
+        args: Self::Args,
+    ) -> Result<Self, Self::Error> {
+        use icu_decimal::{
+            options::{DecimalFormatterOptions, GroupingStrategy},
+            DecimalFormatter,
+        };
+        use icu_experimental::dimension::{
+            currency::formatter::CurrencyFormatter,
+            percent::formatter::PercentFormatter,
+        };
+
+        let locale = lang
+            .to_string()
+            .parse::<icu_locale_core::Locale>()
+            .map_err(|_| ())?;
+        let decimal_formatter = || {
+            let grouping_strategy = match args.use_grouping {
+                true => GroupingStrategy::Auto,
+                false => GroupingStrategy::Never,
+            };
+            DecimalFormatter::try_new(
+                (&locale).into(),
+                DecimalFormatterOptions::from(grouping_strategy),
+            )
+            .map_err(|_| ())
+        };
+
+        let formatter = match &args.style {
+            Style::Decimal => IcuFormatter::Decimal(decimal_formatter()?),
+            Style::Percent => IcuFormatter::Percent(
+                PercentFormatter::try_new_with_decimal_formatter(
+                    (&locale).into(),
+                    decimal_formatter()?,
+                    Default::default(),
+                )
+                .map_err(|_| ())?,
+            ),
+            Style::Currency(currency, display) => {
+                let currency = currency.parse().map_err(|_| ())?;
+                let prefs = (&locale).into();
+                IcuFormatter::Currency(
+                    match display {
+                        FluentNumberCurrencyDisplayStyle::Symbol => {
+                            CurrencyFormatter::try_new_symbol(
+                                prefs,
+                                currency,
+                                Default::default(),
+                            )
+                        }
+                        FluentNumberCurrencyDisplayStyle::Code => {
+                            CurrencyFormatter::try_new_code(
+                                prefs,
+                                currency,
+                                Default::default(),
+                            )
+                        }
+                        FluentNumberCurrencyDisplayStyle::Name => {
+                            CurrencyFormatter::try_new_name(prefs, currency)
+                        }
+                    }
+                    .map_err(|_| ())?,
+                )
+            }
+            Style::Unit(unit, display) => {
+                match UnitFormatter::try_new(&locale, unit, *display) {
+                    Some(formatter) => IcuFormatter::Unit(formatter),
+                    None => IcuFormatter::UnitIdentifier(
+                        decimal_formatter()?,
+                        unit.clone(),
+                    ),
+                }
+            }
+        };
+
+        Ok(Self { args, formatter })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn construct(
+        lang: LanguageIdentifier,
+        args: Self::Args,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            args,
+            locale: lang.to_string(),
+        })
+    }
+}
+
+impl NumberFormatter {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn format(&self, number: &FluentNumber) -> Option<String> {
+        use fixed_decimal::{
+            Decimal, FloatPrecision, SignedRoundingMode, UnsignedRoundingMode,
+        };
+
+        let options = &number.options;
+        let mut value =
+            Decimal::try_from_f64(number.value, FloatPrecision::RoundTrip)
+                .ok()?;
+        if self.args.style == Style::Percent {
+            value.multiply_pow10(2);
+            value.trim_start();
+        }
+
+        let rounding_mode =
+            SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfExpand);
+        if options.minimum_significant_digits.is_some()
+            || options.maximum_significant_digits.is_some()
+        {
+            let magnitude = value.nonzero_magnitude_start();
+            if let Some(max) = options.maximum_significant_digits {
+                value
+                    .round_with_mode(magnitude - max as i16 + 1, rounding_mode);
+            }
+            if let Some(min) = options.minimum_significant_digits {
+                value.pad_end(magnitude - min as i16 + 1);
+            }
+        } else if !matches!(self.args.style, Style::Currency(..))
+            || options.minimum_fraction_digits.is_some()
+            || options.maximum_fraction_digits.is_some()
+        {
+            // currencies are rounded to their digits by default
+            let (default_min, default_max) = self.args.style.fraction_digits();
+            let min = options.minimum_fraction_digits.unwrap_or(default_min);
+            let max = options
+                .maximum_fraction_digits
+                .unwrap_or(default_max.max(min));
+            value.round_with_mode(-(max as i16), rounding_mode);
+            value.trim_end();
+            value.pad_end(-(min as i16));
+        }
+        if let Some(min) = options.minimum_integer_digits {
+            value.pad_start(min as i16 - 1);
+        }
+
+        Some(match &self.formatter {
+            IcuFormatter::Decimal(formatter) => {
+                formatter.format(&value).to_string()
+            }
+            IcuFormatter::Percent(formatter) => {
+                formatter.format(&value).to_string()
+            }
+            IcuFormatter::Currency(formatter) => {
+                formatter.format_fixed_decimal(&value).to_string()
+            }
+            IcuFormatter::Unit(formatter) => formatter.format(&value),
+            IcuFormatter::UnitIdentifier(formatter, unit) => {
+                format!("{} {unit}", formatter.format(&value))
+            }
+        })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn format(&self, number: &FluentNumber) -> Option<String> {
+        use crate::intl::set_option;
+        use js_sys::{Intl, Object};
+        use wasm_bindgen::JsValue;
+
+        let options = &number.options;
+        let js_options = Object::new();
+        let set = |key: &str, value: JsValue| {
+            set_option(&js_options, key, value);
+        };
+        match &self.args.style {
+            Style::Decimal => {}
+            Style::Percent => set("style", "percent".into()),
+            Style::Currency(currency, display) => {
+                set("style", "currency".into());
+                set("currency", currency.into());
+                set(
+                    "currencyDisplay",
+                    match display {
+                        FluentNumberCurrencyDisplayStyle::Symbol => "symbol",
+                        FluentNumberCurrencyDisplayStyle::Code => "code",
+                        FluentNumberCurrencyDisplayStyle::Name => "name",
+                    }
+                    .into(),
+                );
+            }
+            Style::Unit(unit, display) => {
+                set("style", "unit".into());
+                set("unit", unit.into());
+                set("unitDisplay", display.as_str().into());
+            }
+        }
+        set("useGrouping", self.args.use_grouping.into());
+        for (key, value) in [
+            ("minimumIntegerDigits", options.minimum_integer_digits),
+            ("minimumFractionDigits", options.minimum_fraction_digits),
+            ("maximumFractionDigits", options.maximum_fraction_digits),
+            (
+                "minimumSignificantDigits",
+                options.minimum_significant_digits,
+            ),
+            (
+                "maximumSignificantDigits",
+                options.maximum_significant_digits,
+            ),
+        ] {
+            if let Some(value) = value {
+                set(key, (value as u32).into());
+            }
+        }
+        if let (Some(min), None) = (
+            options.minimum_fraction_digits,
+            options.maximum_fraction_digits,
+        ) {
+            // `Intl.NumberFormat` throws if the minimum is greater than
+            // the default maximum
+            let (_, default_max) = self.args.style.fraction_digits();
+            set(
+                "maximumFractionDigits",
+                (default_max.max(min) as u32).into(),
+            );
+        }
+
+        let formatter = crate::intl::construct::<Intl::NumberFormat>(
+            "NumberFormat",
+            &self.locale,
+            &js_options,
+        )?;
+        formatter
+            .format()
+            .call1(&JsValue::NULL, &number.value.into())
+            .ok()?
+            .as_string()
+    }
+}
@@ -0,0 +1,129 @@
+//! Pseudolocalization.
+//!
+//! Pseudolocales are generated from the translations of the default language
+//! when enabled with the `pseudolocales` parameter of [`leptos_fluent!`].
+//! They help to spot hard-coded strings, which are not transformed, and
+//! layouts that break with longer or right-to-left texts:
+//!
+//! - `en-XA`: accented and expanded text, like `Ħḗḗŀŀǿǿ`.
+//! - `ar-XB`: mirrored right-to-left text.
+//!
+//! Only the text of the messages is transformed, the values of the
+//! variables are preserved.
+//!
+//! [`leptos_fluent!`]: crate::leptos_fluent
+
+use fluent_templates::LanguageIdentifier;
+use std::borrow::Cow;
+
+/// Accented and expanded pseudolocale.
+pub const EN_XA: &str = "en-XA";
+/// Mirrored right-to-left pseudolocale.
+pub const AR_XB: &str = "ar-XB";
+
+const ACCENTED_UPPERCASE: [char; 26] = [
+    'Ȧ', 'Ɓ', 'Ƈ', 'Ḓ', 'Ḗ', 'Ƒ', 'Ɠ', 'Ħ', 'Ī', 'Ĵ', 'Ķ', 'Ŀ', 'Ḿ', 'Ƞ', 'Ǿ',
+    'Ƥ', 'Ɋ', 'Ř', 'Ş', 'Ŧ', 'Ŭ', 'Ṽ', 'Ẇ', 'Ẋ', 'Ẏ', 'Ẑ',
+];
+const ACCENTED_LOWERCASE: [char; 26] = [
+    'ȧ', 'ƀ', 'ƈ', 'ḓ', 'ḗ', 'ƒ', 'ɠ', 'ħ', 'ī', 'ĵ', 'ķ', 'ŀ', 'ḿ', 'ƞ', 'ǿ',
+    'ƥ', 'ɋ', 'ř', 'ş', 'ŧ', 'ŭ', 'ṽ', 'ẇ', 'ẋ', 'ẏ', 'ẑ',
+];
+const FLIPPED_UPPERCASE: [char; 26] = [
+    '∀', 'Ԑ', 'Ↄ', 'ᗡ', 'Ǝ', 'Ⅎ', '⅁', 'H', 'I', 'ſ', 'Ӽ', '⅂', 'W', 'N', 'O',
+    'Ԁ', 'Ò', 'ᴚ', 'S', '⊥', '∩', 'Ʌ', 'M', 'X', '⅄', 'Z',
+];
+const FLIPPED_LOWERCASE: [char; 26] = [
+    'ɐ', 'q', 'ɔ', 'p', 'ǝ', 'ɟ', 'ƃ', 'ɥ', 'ı', 'ɾ', 'ʞ', 'ʅ', 'ɯ', 'u', 'o',
+    'd', 'b', 'ɹ', 's', 'ʇ', 'n', 'ʌ', 'ʍ', 'x', 'ʎ', 'z',
+];
+
+/// Replace the ASCII letters of a text.
+fn replace_letters(
+    text: &str,
+    uppercase: &[char; 26],
+    lowercase: &[char; 26],
+    elongate: bool,
+) -> String {
+    let mut result = String::with_capacity(text.len() * 2);
+    for c in text.chars() {
+        let replaced = match c {
+            'A'..='Z' => uppercase[(c as u8 - b'A') as usize],
+            'a'..='z' => lowercase[(c as u8 - b'a') as usize],
+            _ => {
+                result.push(c);
+                continue;
+            }
+        };
+        result.push(replaced);
+        if elongate && "aeiouyAEIOUY".contains(c) {
+            result.push(replaced);
+        }
+    }
+    result
+}
+
+/// Accent the letters of a text and duplicate its vowels.
+///
+/// ```rust
+/// assert_eq!(leptos_fluent::pseudo::accented("Hello"), "Ħḗḗŀŀǿǿ");
+/// ```
+pub fn accented(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(|c| c.is_ascii_alphabetic()) {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(replace_letters(
+        text,
+        &ACCENTED_UPPERCASE,
+        &ACCENTED_LOWERCASE,
+        true,
+    ))
+}
+
+/// Flip the letters of a text and force its right-to-left display.
+///
+/// ```rust
+/// assert_eq!(
+///     leptos_fluent::pseudo::bidi("Hello"),
+///     "\u{202e}Hǝʅʅo\u{202c}",
+/// );
+/// ```
+pub fn bidi(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(|c| c.is_ascii_alphabetic()) {
+        return Cow::Borrowed(text);
+    }
+    Cow::Owned(format!(
+        "\u{202e}{}\u{202c}",
+        replace_letters(text, &FLIPPED_UPPERCASE, &FLIPPED_LOWERCASE, false),
+    ))
+}
+
+/// Transform of the texts of a pseudolocale.
+pub(crate) fn transform(
+    lang_id: &LanguageIdentifier,
+) -> Option<fn(&str) -> Cow<'_, str>> {
+    match pseudolocale(lang_id)? {
+        EN_XA => Some(accented),
+        _ => Some(bidi),
+    }
+}
+
+/// Whether a language identifier is a pseudolocale.
+pub(crate) fn is_pseudolocale(lang_id: &LanguageIdentifier) -> bool {
+    pseudolocale(lang_id).is_some()
+}
+
+/// Pseudolocale identified by a language identifier, if any.
+///
+/// Only the exact `en-XA` and `ar-XB` identifiers are pseudolocales, so
+/// other languages of the `XA` and `XB` regions are translated as usual.
+fn pseudolocale(lang_id: &LanguageIdentifier) -> Option<&'static str> {
+    if lang_id.script.is_some() || lang_id.variants().len() > 0 {
+        return None;
+    }
+    match (lang_id.language.as_str(), lang_id.region?.as_str()) {
+        ("en", "XA") => Some(EN_XA),
+        ("ar", "XB") => Some(AR_XB),
+        _ => None,
+    }
+}
@@ -0,0 +1,198 @@
+//! Translated route slugs.
+//!
+//! The segments of the routes are translated by Fluent messages with the
+//! canonical segment prefixed by `route-` as identifier:
+//!
+//! ```ftl
+//! # locales/es/main.ftl
+//! route-pricing = precios
+//! ```
+//!
+//! The canonical segments are collected by `leptos_fluent!` from the
+//! messages of its `locales`, so slugs of segments only defined in
+//! resources added at runtime are not recognized.
+//!
+//! Declare the translated segments of the routes of `leptos_router` with
+//! [`LocalizedSegment`], which matches the canonical segment and its slugs
+//! in all the languages, so `/es/precios` and `/en/pricing` render the same
+//! route:
+//!
+//! ```rust,ignore
+//! use leptos::prelude::*;
+//! use leptos_fluent::routes::LocalizedSegment;
+//! use leptos_router::{components::Route, ParamSegment};
+//!
+//! view! {
+//!     <Route
+//!         path=(ParamSegment("lang"), LocalizedSegment::new("pricing"))
+//!         view=Pricing
+//!     />
+//! }
+//! ```
+//!
+//! Links built by [`localized_href`] and [`LocalizedA`] use the slugs of
+//! the current language and, with `set_language_to_url_path`, the URL is
+//! switched to the slugs of the new language when it changes.
+//!
+//! The routes are matched by the router of the application, so servers must
+//! render the application for paths with slugs, like the
+//! `file_and_error_handler` fallback of `leptos_axum` does.
+//!
+//! [`localized_href`]: crate::localized_href
+//! [`LocalizedA`]: crate::components::LocalizedA
+
+use crate::url::{percent_decode, percent_encode_segment};
+use crate::{I18n, Language};
+use leptos::prelude::{use_context, GetValue};
+use leptos_router::{PartialPathMatch, PathSegment, PossibleRouteMatch};
+use std::collections::HashMap;
+
+/// Prefix of the identifiers of the messages that translate route segments.
+pub const MESSAGE_PREFIX: &str = "route-";
+
+/// Slugs of the canonical route segments in a language and the canonical
+/// segments of the slugs.
+#[derive(Debug, Default)]
+pub(crate) struct RouteSlugs {
+    slugs: HashMap<&'static str, String>,
+    segments: HashMap<String, &'static str>,
+}
+
+impl RouteSlugs {
+    /// Resolve the slugs of some canonical segments in a language.
+    ///
+    /// Slugs are resolved without isolation marks, which are not valid in
+    /// paths.
+    pub(crate) fn new(
+        i18n: &I18n,
+        language: &'static Language,
+        segments: &'static [&'static str],
+    ) -> Self {
+        let i18n = i18n.with_use_isolating(false);
+        let mut route_slugs = Self::default();
+        for segment in segments {
+            let Ok(slug) = i18n.resolve_in(
+                language,
+                &format!("{MESSAGE_PREFIX}{segment}"),
+                None,
+            ) else {
+                continue;
+            };
+            route_slugs.segments.insert(slug.clone(), segment);
+            route_slugs.slugs.insert(segment, slug);
+        }
+        route_slugs
+    }
+}
+
+/// Slug of a canonical segment in a language.
+///
+/// Returns the canonical segment if the `route-{segment}` message is not
+/// defined.
+pub fn slug(i18n: &I18n, language: &'static Language, segment: &str) -> String {
+    i18n.route_slugs(language)
+        .slugs
+        .get(segment)
+        .cloned()
+        .unwrap_or_else(|| segment.to_string())
+}
+
+/// Canonical segment of a slug in any language.
+///
+/// The canonical segments are those translated by the `route-*` messages
+/// of the `locales` of `leptos_fluent!`. The slug is percent-decoded, so
+/// segments of URL paths can be passed as is.
+pub fn canonical_segment(i18n: &I18n, slug: &str) -> Option<&'static str> {
+    let slug = &percent_decode(slug);
+    let segments = i18n.route_segments.get_value();
+    segments
+        .iter()
+        .copied()
+        .find(|segment| *segment == slug)
+        .or_else(|| {
+            i18n.languages.iter().find_map(|language| {
+                i18n.route_slugs(language).segments.get(slug).copied()
+            })
+        })
+}
+
+/// Translate the segments of a path without language prefix to a language,
+/// or to the canonical segments for `None`.
+///
+/// The translated segments are percent-encoded. The query and fragment of
+/// the path are preserved.
+pub fn localize_path(
+    i18n: &I18n,
+    language: Option<&'static Language>,
+    path: &str,
+) -> String {
+    let (pathname, suffix) = path
+        .find(['?', '#'])
+        .map_or((path, ""), |index| path.split_at(index));
+    let pathname = pathname
+        .split('/')
+        .map(|segment| {
+            if segment.is_empty() {
+                return segment.to_string();
+            }
+            let Some(canonical) = canonical_segment(i18n, segment) else {
+                return segment.to_string();
+            };
+            percent_encode_segment(&match language {
+                Some(language) => slug(i18n, language, canonical),
+                None => canonical.to_string(),
+            })
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+    format!("{pathname}{suffix}")
+}
+
+/// Route segment of `leptos_router` translated to the languages of the
+/// application.
+///
+/// Matches the canonical segment and its slugs in all the languages, which
+/// are defined by `route-{segment}` messages.
+#[derive(Clone, Debug)]
+pub struct LocalizedSegment {
+    segment: &'static str,
+    i18n: Option<I18n>,
+}
+
+impl LocalizedSegment {
+    /// Create a segment translated to the languages of the [`I18n`] context.
+    pub fn new(segment: &'static str) -> Self {
+        Self {
+            segment,
+            i18n: use_context::<I18n>(),
+        }
+    }
+
+    /// Canonical segment.
+    pub fn segment(&self) -> &'static str {
+        self.segment
+    }
+}
+
+impl PossibleRouteMatch for LocalizedSegment {
+    fn optional(&self) -> bool {
+        false
+    }
+
+    fn test<'a>(&self, path: &'a str) -> Option<PartialPathMatch<'a>> {
+        let rest = path.strip_prefix('/')?;
+        let candidate = rest.split('/').next().unwrap_or_default();
+        let matches = candidate == self.segment
+            || self.i18n.is_some_and(|i18n| {
+                canonical_segment(&i18n, candidate) == Some(self.segment)
+            });
+        matches.then(|| {
+            let (matched, remaining) = path.split_at(candidate.len() + 1);
+            PartialPathMatch::new(remaining, vec![], matched)
+        })
+    }
+
+    fn generate_path(&self, path: &mut Vec<PathSegment>) {
+        path.push(PathSegment::Static(self.segment.into()))
+    }
+}
@@ -0,0 +1,294 @@
+//! Translations on the server.
+//!
+//! Only available with the `ssr` feature.
+//!
+//! [`server_i18n!`](crate::server_i18n) accepts the parameters of
+//! `leptos_fluent!` and creates a [`ServerI18n`] handle with its languages
+//! and translations. Provided as context to the server functions, they can
+//! localize errors, emails and other messages in the language of the
+//! request:
+//!
+//! ```rust,ignore
+//! use leptos::prelude::*;
+//! use leptos_fluent::{server::ServerI18n, server_i18n, tr};
+//!
+//! pub fn server_i18n() -> ServerI18n {
+//!     server_i18n! {
+//!         locales: "./locales",
+//!         default_language: "en",
+//!         initial_language_from_cookie: true,
+//!         initial_language_from_accept_language_header: true,
+//!     }
+//! }
+//!
+//! // at the startup of the server, with `leptos_axum`
+//! let server_i18n = server_i18n();
+//! let app = Router::new().route(
+//!     "/api/{*fn_name}",
+//!     post(move |req| {
+//!         let server_i18n = server_i18n.clone();
+//!         leptos_axum::handle_server_fns_with_context(
+//!             move || provide_context(server_i18n.clone()),
+//!             req,
+//!         )
+//!     }),
+//! );
+//!
+//! #[server]
+//! pub async fn subscribe(email: String) -> Result<String, ServerFnError> {
+//!     leptos_fluent::server::i18n_from_request()
+//!         .ok_or_else(|| ServerFnError::new("ServerI18n not provided"))?;
+//!     if !email.contains('@') {
+//!         return Err(ServerFnError::new(tr!("invalid-email")));
+//!     }
+//!     Ok(tr!("subscribed", {"email" => email}))
+//! }
+//! ```
+//!
+//! `leptos_fluent!` also provides a [`ServerI18n`] with its parameters as
+//! context of the server renders, so server functions called while
+//! rendering don't need it to be provided.
+//!
+//! The language is negotiated from the URL parameter, the URL path, the
+//! cookie and the `Accept-Language` header of the request, in that order,
+//! with the strategies enabled by the `initial_language_from_*` parameters,
+//! falling back to the default language.
+//!
+//! The request is read through the [`RequestLanguageSource`] trait from the
+//! context provided by `leptos_actix` or `leptos_axum`, with the `actix` or
+//! `axum` feature enabled, or from a [`RequestSource`] context for other
+//! servers and tests:
+//!
+//! ```rust,ignore
+//! use leptos::prelude::provide_context;
+//! use leptos_fluent::server::RequestSource;
+//!
+//! let request = http::Request::builder()
+//!     .uri("/es/pricing")
+//!     .header("Accept-Language", "es-ES,es;q=0.9")
+//!     .body(())
+//!     .unwrap();
+//! provide_context(RequestSource::new(request));
+//! ```
+//!
+//! Resources added at runtime to other contexts with
+//! [`I18n::add_resource`] are not available on the server, and namespaced
+//! contexts don't provide a [`ServerI18n`].
+
+use crate::{I18n, Language};
+use leptos::prelude::{provide_context, use_context};
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// Request from which the language is negotiated on the server.
+///
+/// Implemented for the requests of `actix-web` with the `actix` feature, for
+/// [`http::Request`] and for [`http::request::Parts`], which are the request
+/// parts provided by `leptos_axum`.
+pub trait RequestLanguageSource {
+    /// Value of a header, if it's valid ASCII.
+    fn header(&self, name: &str) -> Option<&str>;
+
+    /// Path of the URI.
+    fn path(&self) -> &str;
+
+    /// Query of the URI, without the leading `?`.
+    fn query(&self) -> Option<&str>;
+
+    /// Value of a cookie, percent-decoded.
+    fn cookie(&self, name: &str) -> Option<String> {
+        self.header("cookie")?.split(';').find_map(|cookie| {
+            let (key, value) = cookie.trim().split_once('=')?;
+            (key == name).then(|| crate::url::percent_decode(value))
+        })
+    }
+
+    /// Value of a parameter of the query.
+    fn url_param(&self, name: &str) -> Option<&str> {
+        self.query()?.split('&').find_map(|pair| {
+            let mut split = pair.splitn(2, '=');
+            (split.next() == Some(name)).then(|| split.next().unwrap_or(""))
+        })
+    }
+
+    /// Path and query of the URI.
+    fn path_and_query(&self) -> String {
+        match self.query() {
+            Some(query) => format!("{}?{}", self.path(), query),
+            None => self.path().to_string(),
+        }
+    }
+
+    /// Languages of the `Accept-Language` header sorted by quality.
+    fn accept_languages(&self) -> Vec<String> {
+        self.header("accept-language")
+            .map(crate::http_header::parse)
+            .unwrap_or_default()
+    }
+}
+
+impl<B> RequestLanguageSource for http::Request<B> {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers().get(name)?.to_str().ok()
+    }
+
+    fn path(&self) -> &str {
+        self.uri().path()
+    }
+
+    fn query(&self) -> Option<&str> {
+        self.uri().query()
+    }
+}
+
+impl RequestLanguageSource for http::request::Parts {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name)?.to_str().ok()
+    }
+
+    fn path(&self) -> &str {
+        self.uri.path()
+    }
+
+    fn query(&self) -> Option<&str> {
+        self.uri.query()
+    }
+}
+
+#[cfg(feature = "actix")]
+impl RequestLanguageSource for actix_web::HttpRequest {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers().get(name)?.to_str().ok()
+    }
+
+    fn path(&self) -> &str {
+        actix_web::HttpRequest::path(self)
+    }
+
+    fn query(&self) -> Option<&str> {
+        Some(self.query_string()).filter(|query| !query.is_empty())
+    }
+}
+
+/// Request provided as context by servers without built-in integration.
+///
+/// Used by `leptos_fluent!` and the functions of this module when there is
+/// no request of `leptos_actix` or `leptos_axum` in the context.
+#[derive(Clone)]
+pub struct RequestSource(Arc<dyn RequestLanguageSource + Send + Sync>);
+
+impl RequestSource {
+    /// Wrap a request to be provided as context.
+    pub fn new(
+        request: impl RequestLanguageSource + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(request))
+    }
+}
+
+impl Deref for RequestSource {
+    type Target = dyn RequestLanguageSource + Send + Sync;
+
+    fn deref(&self) -> &Self::Target {
+        &*self.0
+    }
+}
+
+/// Negotiates the language of the current request.
+type RequestLanguage = Box<dyn Fn() -> Option<&'static Language> + Send + Sync>;
+
+/// Handle to translate in the language of the requests on the server.
+///
+/// Created with [`server_i18n!`](crate::server_i18n) at the startup of the
+/// server and provided as context to the server functions. Also provided by
+/// `leptos_fluent!` as context of the server renders.
+#[derive(Clone)]
+pub struct ServerI18n(Arc<ServerI18nInner>);
+
+struct ServerI18nInner {
+    /// Creates the context with the translations in the current owner.
+    i18n: Box<dyn Fn() -> I18n + Send + Sync>,
+    default_language: &'static Language,
+    request_language: RequestLanguage,
+}
+
+impl ServerI18n {
+    #[doc(hidden)]
+    pub fn new(
+        i18n: impl Fn() -> I18n + Send + Sync + 'static,
+        default_language: &'static Language,
+        request_language: impl Fn() -> Option<&'static Language>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        Self(Arc::new(ServerI18nInner {
+            i18n: Box::new(i18n),
+            default_language,
+            request_language: Box::new(request_language),
+        }))
+    }
+
+    /// Language of the current request.
+    ///
+    /// Negotiated with the initial language strategies enabled for the
+    /// request, falling back to the default language.
+    pub fn language_from_request(&self) -> &'static Language {
+        let language =
+            (self.0.request_language)().unwrap_or(self.0.default_language);
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            "Language negotiated from the request: {}",
+            language.id
+        );
+
+        language
+    }
+
+    /// Create an [`I18n`] context in the language of the current request.
+    ///
+    /// The context is also provided to the current reactive owner, so the
+    /// translation macros can be used after calling it.
+    pub fn i18n_from_request(&self) -> I18n {
+        let i18n = (self.0.i18n)().scope(self.language_from_request());
+        provide_context(i18n);
+        i18n
+    }
+}
+
+/// Path and query of the request of the current server render.
+///
+/// Read like the request of `leptos_fluent!`, from the context provided by
+/// `leptos_actix` or `leptos_axum` with the `actix` or `axum` features, or
+/// from a [`RequestSource`] context.
+pub(crate) fn request_path_and_query() -> Option<String> {
+    #[cfg(feature = "actix")]
+    if let Some(req) = use_context::<leptos_actix::Request>() {
+        return Some(req.path_and_query());
+    }
+
+    #[cfg(feature = "axum")]
+    if let Some(req) = use_context::<http::request::Parts>() {
+        return Some(req.path_and_query());
+    }
+
+    use_context::<RequestSource>().map(|req| req.path_and_query())
+}
+
+/// Language of the current request.
+///
+/// Returns `None` if there is no [`ServerI18n`] in the context.
+pub fn language_from_request() -> Option<&'static Language> {
+    use_context::<ServerI18n>().map(|server| server.language_from_request())
+}
+
+/// Create an [`I18n`] context in the language of the current request.
+///
+/// The context is also provided to the current reactive owner, so the
+/// translation macros can be used inside server functions after calling it.
+///
+/// Returns `None` if there is no [`ServerI18n`] in the context.
+pub fn i18n_from_request() -> Option<I18n> {
+    use_context::<ServerI18n>().map(|server| server.i18n_from_request())
+}
@@ -1,3 +1,92 @@
+/// Replace the current URL of the browser with a path of the application.
+///
+/// Navigates through `leptos_router` when called inside a `<Router/>` with
+/// the `router` feature, so its location and routes follow the new URL, or
+/// replaces the history state otherwise.
+#[cfg(not(feature = "ssr"))]
+fn replace(href: &str) -> Result<(), wasm_bindgen::JsValue> {
+    #[cfg(feature = "router")]
+    if leptos::prelude::use_context::<leptos_router::location::BrowserUrl>()
+        .is_some()
+    {
+        use leptos_router::{hooks::use_navigate, NavigateOptions};
+
+        use_navigate()(
+            href,
+            NavigateOptions {
+                replace: true,
+                scroll: false,
+                ..Default::default()
+            },
+        );
+        return Ok(());
+    }
+
+    leptos::prelude::window().history()?.replace_state_with_url(
+        &wasm_bindgen::JsValue::NULL,
+        "",
+        Some(href),
+    )
+}
+
+/// Decode the percent-encoded bytes of a value.
+#[cfg(any(feature = "ssr", feature = "router"))]
+pub(crate) fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).filter(|hex| {
+            bytes[i] == b'%' && hex.iter().all(u8::is_ascii_hexdigit)
+        });
+        match hex {
+            Some(hex) => {
+                let hex = std::str::from_utf8(hex).unwrap();
+                decoded.push(u8::from_str_radix(hex, 16).unwrap());
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Percent-encode the characters of a path segment that are not allowed
+/// unencoded in URLs.
+#[cfg(feature = "router")]
+pub(crate) fn percent_encode_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'.'
+            | b'_'
+            | b'~'
+            | b'!'
+            | b'$'
+            | b'&'
+            | b'\''
+            | b'('
+            | b')'
+            | b'*'
+            | b'+'
+            | b','
+            | b';'
+            | b'='
+            | b':'
+            | b'@' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
 pub mod param {
     #[cfg_attr(
         feature = "tracing",
@@ -72,24 +161,14 @@ pub mod param {
             };
             url.search_params().set(k, v);
 
-            if let Ok(history) = window.history() {
-                if let Err(_error) = history.replace_state_with_url(
-                    &wasm_bindgen::JsValue::NULL,
-                    "",
-                    Some(&url.href()),
-                ) {
-                    #[cfg(feature = "tracing")]
-                    tracing::trace!(
-                        "Failed to replace the history state when setting URL parameter \"{}\": {:?}",
-                        k,
-                        _error
-                    );
-                }
-            } else {
+            let href =
+                format!("{}{}{}", url.pathname(), url.search(), url.hash());
+            if let Err(_error) = super::replace(&href) {
                 #[cfg(feature = "tracing")]
                 tracing::trace!(
-                    "Failed to get the history from the browser when setting URL parameter \"{}\"",
-                    k
+                    "Failed to replace the history state when setting URL parameter \"{}\": {:?}",
+                    k,
+                    _error
                 );
             }
 
@@ -143,24 +222,14 @@ pub mod param {
             };
             url.search_params().delete(k);
 
-            if let Ok(history) = window.history() {
-                if let Err(_error) = history.replace_state_with_url(
-                    &wasm_bindgen::JsValue::NULL,
-                    "",
-                    Some(&url.href()),
-                ) {
-                    #[cfg(feature = "tracing")]
-                    tracing::trace!(
-                        "Failed to replace the history state when deleting URL parameter \"{}\": {:?}",
-                        k,
-                        _error
-                    );
-                }
-            } else {
+            let href =
+                format!("{}{}{}", url.pathname(), url.search(), url.hash());
+            if let Err(_error) = super::replace(&href) {
                 #[cfg(feature = "tracing")]
                 tracing::trace!(
-                    "Failed to get the history from the browser when deleting URL parameter \"{}\"",
-                    k
+                    "Failed to replace the history state when deleting URL parameter \"{}\": {:?}",
+                    k,
+                    _error
                 );
             }
 
@@ -179,6 +248,9 @@ pub mod param {
 }
 
 pub mod path {
+    use crate::{I18n, Language};
+    use leptos::prelude::Get;
+
     #[cfg_attr(
         feature = "tracing",
         tracing::instrument(level = "trace", skip_all)
@@ -191,4 +263,110 @@ pub mod path {
 
         None
     }
+
+    /// Get the language from the first segment of an URL path.
+    ///
+    /// Used as the extractor of `leptos_fluent!` with `url_path: true`.
+    pub fn language(path: &str) -> &str {
+        path.trim_start_matches('/')
+            .split(['/', '?', '#'])
+            .next()
+            .unwrap_or("")
+    }
+
+    /// Get a path with a language as its first segment, replacing the
+    /// language of the path if any, or without language for `None`.
+    pub fn localize(
+        path: &str,
+        language_id: Option<&str>,
+        languages: &[&Language],
+    ) -> String {
+        let trimmed = path.trim_start_matches('/');
+        let rest = if is_localized(trimmed, languages) {
+            trimmed[language(trimmed).len()..].to_string()
+        } else if trimmed.is_empty() || trimmed.starts_with(['?', '#']) {
+            trimmed.to_string()
+        } else {
+            format!("/{trimmed}")
+        };
+        match language_id {
+            Some(language_id) => format!("/{language_id}{rest}"),
+            None if rest.starts_with('/') => rest,
+            None => format!("/{rest}"),
+        }
+    }
+
+    /// Localize an absolute path of the application to a language, or to
+    /// the path without language for `None`, translating its route slugs
+    /// with the `router` feature.
+    ///
+    /// Relative paths and URLs of other origins are returned unchanged.
+    pub fn localize_href(
+        i18n: &I18n,
+        language: Option<&'static Language>,
+        href: &str,
+    ) -> String {
+        if !href.starts_with('/') || href.starts_with("//") {
+            return href.to_string();
+        }
+        let path = localize(href, None, i18n.languages);
+        #[cfg(feature = "router")]
+        let path = crate::routes::localize_path(i18n, language, &path);
+        localize(&path, language.map(|lang| lang.id), i18n.languages)
+    }
+
+    /// Whether the first segment of a path is one of the languages.
+    pub fn is_localized(path: &str, languages: &[&Language]) -> bool {
+        let first = language(path);
+        languages
+            .iter()
+            .any(|lang| lang.id.eq_ignore_ascii_case(first))
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "trace", skip_all)
+    )]
+    pub fn set(i18n: &I18n) {
+        let language = i18n.language.get();
+        #[cfg(not(feature = "ssr"))]
+        {
+            let location = leptos::prelude::window().location();
+            let (Ok(pathname), Ok(search), Ok(hash)) =
+                (location.pathname(), location.search(), location.hash())
+            else {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(
+                    "Failed to get the location from the browser when setting the language {:?} to the URL path",
+                    language.id
+                );
+                return;
+            };
+            let href = localize_href(
+                i18n,
+                Some(language),
+                &format!("{pathname}{search}{hash}"),
+            );
+            if href == format!("{pathname}{search}{hash}") {
+                return;
+            }
+
+            if let Err(_error) = super::replace(&href) {
+                #[cfg(feature = "tracing")]
+                tracing::trace!(
+                    "Failed to replace the history state when setting the language {:?} to the URL path: {:?}",
+                    language.id,
+                    _error
+                );
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::trace!("Set URL path in browser to {:?}", href);
+        };
+
+        #[cfg(feature = "ssr")]
+        {
+            _ = language;
+        };
+    }
 }
@@ -0,0 +1,61 @@
+//! Dates and relative times are formatted with the data of the CLDR for
+//! each language.
+#![cfg(all(feature = "chrono", feature = "time"))]
+
+use leptos::prelude::*;
+use leptos_fluent::{datetime::FluentDateTime, leptos_fluent, tr, I18n};
+
+#[test]
+#[allow(clippy::unused_unit)]
+fn format_datetimes() {
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            locales: "tests/locales_datetime",
+            default_language: "en",
+            customise: |bundle| bundle.set_use_isolating(false),
+        };
+        let i18n = expect_context::<I18n>();
+
+        // 2025-10-09T08:53:20Z
+        let chrono_date = chrono::DateTime::from_timestamp(1_760_000_000, 0).unwrap();
+        let time_date =
+            time::OffsetDateTime::from_unix_timestamp(1_760_000_000).unwrap();
+
+        assert_eq!(
+            tr!("updated", { "date" => FluentDateTime::from(chrono_date) }),
+            "Updated on October 9, 2025"
+        );
+        assert_eq!(
+            tr!("updated", { "date" => 1_760_000_000_000_i64 }),
+            "Updated on October 9, 2025"
+        );
+        assert_eq!(
+            tr!("meeting", { "date" => FluentDateTime::from(time_date) }),
+            "Meeting at 04:53"
+        );
+        assert_eq!(
+            tr!("birthday", { "date" => FluentDateTime::from(time::Date::from_calendar_date(2000, time::Month::February, 29).unwrap()) }),
+            "Birthday: Feb 29, 2000"
+        );
+        assert_eq!(tr!("due", { "days" => 1 }), "Due tomorrow");
+        assert_eq!(tr!("due", { "days" => -3 }), "Due 3 days ago");
+        assert_eq!(tr!("elapsed", { "hours" => 2 }), "in 2 hours");
+
+        i18n.language
+            .set(i18n.languages.iter().find(|lang| lang.id == "es").unwrap());
+        assert_eq!(
+            tr!("updated", { "date" => FluentDateTime::from(chrono_date.date_naive()) }),
+            "Actualizado el 9 de octubre de 2025"
+        );
+        assert_eq!(tr!("due", { "days" => 1 }), "Vence mañana");
+
+        i18n.add_resource(
+            "es",
+            "runtime = { RELATIVETIME($n, unit: \"minute\", style: \"short\") }"
+                .to_string(),
+        )
+        .unwrap();
+        assert_eq!(tr!("runtime", { "n" => -5 }), "hace 5 min");
+    });
+}
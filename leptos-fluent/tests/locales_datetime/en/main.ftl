@@ -0,0 +1,5 @@
+updated = Updated on { DATETIME($date, dateStyle: "long") }
+meeting = Meeting at { DATETIME($date, timeStyle: "short", hourCycle: "h23", timeZone: "America/New_York") }
+birthday = Birthday: { $date }
+due = Due { RELATIVETIME($days, unit: "day", numeric: "auto") }
+elapsed = { RELATIVETIME($hours, unit: "hours") }
@@ -0,0 +1,5 @@
+updated = Actualizado el { DATETIME($date, dateStyle: "long") }
+meeting = Reunión a las { DATETIME($date, timeStyle: "short", hourCycle: "h23", timeZone: "America/New_York") }
+birthday = Cumpleaños: { $date }
+due = Vence { RELATIVETIME($days, unit: "day", numeric: "auto") }
+elapsed = { RELATIVETIME($hours, unit: "hours") }
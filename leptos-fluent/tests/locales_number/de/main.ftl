@@ -0,0 +1,8 @@
+total = Gesamt: { NUMBER($amount, style: "currency", currency: "EUR") }
+progress = { NUMBER($ratio, style: "percent") } abgeschlossen
+distance = { NUMBER($distance, style: "unit", unit: "kilometer") }
+price = { NUMBER($price, minimumFractionDigits: 2) }
+items = { NUMBER($count) ->
+    [one] Ein Artikel
+   *[other] { $count } Artikel
+}
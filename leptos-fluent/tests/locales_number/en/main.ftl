@@ -0,0 +1,8 @@
+total = Total: { NUMBER($amount, style: "currency", currency: "EUR") }
+progress = { NUMBER($ratio, style: "percent") } completed
+distance = { NUMBER($distance, style: "unit", unit: "kilometer") }
+price = { NUMBER($price, minimumFractionDigits: 2) }
+items = { NUMBER($count) ->
+    [one] One item
+   *[other] { $count } items
+}
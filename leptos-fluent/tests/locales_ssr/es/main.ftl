@@ -0,0 +1 @@
+welcome = Bienvenido
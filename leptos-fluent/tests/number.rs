@@ -0,0 +1,46 @@
+//! Numbers are formatted with the data of the CLDR for each language.
+#![cfg(feature = "number")]
+
+use leptos::prelude::*;
+use leptos_fluent::{leptos_fluent, tr, I18n};
+
+#[test]
+#[allow(clippy::unused_unit)]
+fn format_numbers() {
+    Owner::new().with(|| {
+        leptos_fluent! {
+            children: (),
+            locales: "tests/locales_number",
+            default_language: "de",
+            customise: |bundle| bundle.set_use_isolating(false),
+        };
+        let i18n = expect_context::<I18n>();
+
+        assert_eq!(tr!("price", { "price" => 1234.5 }), "1.234,50");
+        assert_eq!(
+            tr!("total", { "amount" => 1234.5 }),
+            "Gesamt: 1.234,50\u{a0}€"
+        );
+        assert_eq!(
+            tr!("progress", { "ratio" => 0.25 }),
+            "25\u{a0}% abgeschlossen"
+        );
+        assert_eq!(tr!("distance", { "distance" => 1.5 }), "1,5 km");
+        assert_eq!(tr!("items", { "count" => 1 }), "Ein Artikel");
+        assert_eq!(tr!("items", { "count" => 1000 }), "1.000 Artikel");
+
+        i18n.language
+            .set(i18n.languages.iter().find(|lang| lang.id == "en").unwrap());
+        assert_eq!(tr!("price", { "price" => 1234.5 }), "1,234.50");
+        assert_eq!(tr!("total", { "amount" => 1234.5 }), "Total: €1,234.50");
+        assert_eq!(tr!("progress", { "ratio" => 0.25 }), "25% completed");
+        assert_eq!(tr!("distance", { "distance" => 1.5 }), "1.5 km");
+
+        i18n.add_resource(
+            "en",
+            "runtime = { NUMBER($n, maximumSignificantDigits: 2) }".to_string(),
+        )
+        .unwrap();
+        assert_eq!(tr!("runtime", { "n" => 12345 }), "12,000");
+    });
+}
@@ -0,0 +1,327 @@
+//! Elements and headers of the responses rendered on the server.
+#![cfg(all(feature = "ssr", feature = "axum"))]
+
+use axum::http::header::{HeaderValue, CONTENT_LANGUAGE, VARY};
+use axum::http::{request::Parts, HeaderMap};
+use futures::StreamExt;
+use leptos::prelude::*;
+use leptos::tachys::view::RenderHtml;
+use leptos_axum::ResponseOptions;
+use leptos_fluent::components::HreflangLinks;
+use leptos_fluent::server::{
+    i18n_from_request, language_from_request, RequestLanguageSource,
+    RequestSource, ServerI18n,
+};
+use leptos_fluent::{leptos_fluent, server_i18n, tr};
+use leptos_meta::ServerMetaContext;
+
+#[component]
+fn I18n(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "tests/locales_ssr",
+        default_language: "en",
+        initial_language_from_url_param: true,
+        sync_hreflang_links: true,
+        hreflang_origin: "https://example.com",
+    }
+}
+
+fn request(uri: &str, headers: &[(&str, &str)]) -> Parts {
+    let mut builder = axum::http::Request::builder().uri(uri);
+    for (name, value) in headers {
+        builder = builder.header(*name, *value);
+    }
+    builder.body(()).unwrap().into_parts().0
+}
+
+/// Render a view for a request, returning the elements of the `<head>`.
+fn render_head<V: RenderHtml + 'static>(
+    request: Parts,
+    view: impl FnOnce() -> V,
+) -> String {
+    _ = any_spawner::Executor::init_futures_executor();
+    Owner::new().with(|| {
+        provide_context(request);
+        let (meta, output) = ServerMetaContext::new();
+        provide_context(meta);
+        let html = format!("<head></head><body>{}</body>", view().to_html());
+        let html = futures::executor::block_on(async move {
+            output
+                .inject_meta_context(futures::stream::iter([html]))
+                .await
+                .collect::<String>()
+                .await
+        });
+        let (head, _) = html["<head>".len()..].split_once("</head>").unwrap();
+        head.to_string()
+    })
+}
+
+#[test]
+fn hreflang_links() {
+    // `sync_hreflang_links` links the path of the request in each language
+    assert_eq!(
+        render_head(request("/about?page=2&lang=es", &[]), || {
+            view! { <I18n><p></p></I18n> }
+        }),
+        concat!(
+            r#"<link href="https://example.com/about?page=2&amp;lang=en" hreflang="en" rel="alternate">"#,
+            r#"<link href="https://example.com/about?page=2&amp;lang=es" hreflang="es" rel="alternate">"#,
+            r#"<link href="https://example.com/about?page=2" hreflang="x-default" rel="alternate">"#,
+        ),
+    );
+
+    // the component prepends the origin and accepts other paths
+    assert_eq!(
+        render_head(request("/", &[]), || {
+            view! {
+                <I18n>
+                    <HreflangLinks
+                        origin="https://example.com"
+                        path="/pricing"
+                        url_param="language"
+                    />
+                </I18n>
+            }
+        }),
+        concat!(
+            r#"<link href="https://example.com/?lang=en" hreflang="en" rel="alternate">"#,
+            r#"<link href="https://example.com/?lang=es" hreflang="es" rel="alternate">"#,
+            r#"<link href="https://example.com/" hreflang="x-default" rel="alternate">"#,
+            r#"<link href="https://example.com/pricing?language=en" hreflang="en" rel="alternate">"#,
+            r#"<link href="https://example.com/pricing?language=es" hreflang="es" rel="alternate">"#,
+            r#"<link href="https://example.com/pricing" hreflang="x-default" rel="alternate">"#,
+        ),
+    );
+
+    // without `path`, the component links the path of the request
+    assert_eq!(
+        render_head(request("/about?lang=es", &[]), || {
+            view! {
+                <I18n>
+                    <HreflangLinks origin="https://example.org" />
+                </I18n>
+            }
+        }),
+        concat!(
+            r#"<link href="https://example.com/about?lang=en" hreflang="en" rel="alternate">"#,
+            r#"<link href="https://example.com/about?lang=es" hreflang="es" rel="alternate">"#,
+            r#"<link href="https://example.com/about" hreflang="x-default" rel="alternate">"#,
+            r#"<link href="https://example.org/about?lang=en" hreflang="en" rel="alternate">"#,
+            r#"<link href="https://example.org/about?lang=es" hreflang="es" rel="alternate">"#,
+            r#"<link href="https://example.org/about" hreflang="x-default" rel="alternate">"#,
+        ),
+    );
+}
+
+#[component]
+fn NegotiatedI18n(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "tests/locales_ssr",
+        default_language: "en",
+        initial_language_from_cookie: true,
+        initial_language_from_accept_language_header: true,
+    }
+}
+
+/// Render a view for a request, returning the headers of the response.
+fn render_headers<V: RenderHtml + 'static>(
+    request: Parts,
+    response: ResponseOptions,
+    view: impl FnOnce() -> V,
+) -> HeaderMap {
+    Owner::new().with(|| {
+        provide_context(request);
+        provide_context(response.clone());
+        _ = view().to_html();
+    });
+    let headers = response.0.read().headers.clone();
+    headers
+}
+
+#[test]
+fn response_headers() {
+    let response = ResponseOptions::default();
+    let headers = render_headers(
+        request("/", &[("accept-language", "es-ES,es;q=0.9")]),
+        response,
+        || view! { <NegotiatedI18n><p></p></NegotiatedI18n> },
+    );
+    assert_eq!(headers[CONTENT_LANGUAGE], "es");
+    assert_eq!(headers[VARY], "Accept-Language, Cookie");
+
+    // the cookie takes precedence over the `Accept-Language` header
+    let headers = render_headers(
+        request("/", &[("cookie", "lf-lang=en"), ("accept-language", "es")]),
+        ResponseOptions::default(),
+        || view! { <NegotiatedI18n><p></p></NegotiatedI18n> },
+    );
+    assert_eq!(headers[CONTENT_LANGUAGE], "en");
+
+    // existing `Vary` headers are kept
+    let response = ResponseOptions::default();
+    response.insert_header(VARY, HeaderValue::from_static("Origin"));
+    let headers = render_headers(
+        request("/", &[("accept-language", "es")]),
+        response,
+        || view! { <NegotiatedI18n><p></p></NegotiatedI18n> },
+    );
+    assert_eq!(
+        headers.get_all(VARY).iter().collect::<Vec<_>>(),
+        ["Origin", "Accept-Language, Cookie"],
+    );
+
+    // without the negotiation strategies only `Content-Language` is set
+    let headers = render_headers(
+        request("/?lang=es", &[]),
+        ResponseOptions::default(),
+        || view! { <I18n><p></p></I18n> },
+    );
+    assert_eq!(headers[CONTENT_LANGUAGE], "es");
+    assert!(headers.get(VARY).is_none());
+}
+
+fn server_i18n() -> ServerI18n {
+    server_i18n! {
+        locales: "tests/locales_ssr",
+        default_language: "en",
+        initial_language_from_cookie: true,
+    }
+}
+
+#[server]
+async fn welcome() -> Result<String, ServerFnError> {
+    i18n_from_request()
+        .ok_or_else(|| ServerFnError::new("ServerI18n not provided"))?;
+    Ok(tr!("welcome"))
+}
+
+/// Call the server function for a request, optionally with a handle.
+fn call_welcome(
+    request: Parts,
+    server_i18n: Option<ServerI18n>,
+) -> Result<String, ServerFnError> {
+    Owner::new().with(|| {
+        provide_context(request);
+        if let Some(server_i18n) = server_i18n {
+            provide_context(server_i18n);
+        }
+        futures::executor::block_on(welcome())
+    })
+}
+
+#[test]
+fn server_functions() {
+    // server functions called before any render use the provided handle
+    let server = server_i18n();
+    let cookie = |cookie| request("/api/welcome", &[("cookie", cookie)]);
+    assert_eq!(
+        call_welcome(cookie("lf-lang=es"), Some(server.clone())).unwrap(),
+        "Bienvenido"
+    );
+    assert!(call_welcome(cookie("lf-lang=es"), None).is_err());
+
+    // cookies are percent-decoded
+    assert_eq!(
+        call_welcome(
+            cookie("theme=dark; lf-lang=%65%73"),
+            Some(server.clone())
+        )
+        .unwrap(),
+        "Bienvenido"
+    );
+
+    // only the initial language strategies enabled are used
+    assert_eq!(
+        call_welcome(
+            request("/api/welcome?lang=es", &[("accept-language", "es")]),
+            Some(server),
+        )
+        .unwrap(),
+        "Welcome"
+    );
+
+    // renders provide a handle with the parameters of `leptos_fluent!`
+    Owner::new().with(|| {
+        provide_context(request("/?lang=es", &[("cookie", "lf-lang=en")]));
+        _ = view! {
+            <I18n>
+                {
+                    let i18n = i18n_from_request().unwrap();
+                    assert_eq!(i18n.language.get_untracked().id, "es");
+                    assert_eq!(i18n.tr("welcome"), "Bienvenido");
+                }
+            </I18n>
+        }
+        .to_html();
+    });
+}
+
+#[test]
+fn server_i18n_contexts() {
+    // the handle must be provided as context
+    Owner::new().with(|| {
+        assert!(i18n_from_request().is_none());
+        assert!(language_from_request().is_none());
+    });
+
+    // outside of a request the default language is used
+    let server = server_i18n();
+    Owner::new().with(|| {
+        provide_context(server.clone());
+        assert_eq!(language_from_request().unwrap().id, "en");
+        let i18n = i18n_from_request().unwrap();
+        assert_eq!(i18n.tr("welcome"), "Welcome");
+        assert_eq!(tr!("welcome"), "Welcome");
+    });
+
+    // each request gets its own context
+    Owner::new().with(|| {
+        provide_context(server);
+        let i18n = i18n_from_request().unwrap();
+        i18n.language.set(i18n.languages[1]);
+        assert_eq!(
+            i18n_from_request().unwrap().language.get_untracked().id,
+            "en"
+        );
+    });
+}
+
+#[test]
+fn request_language_source() {
+    // requests are read by the SSR strategies through `RequestLanguageSource`
+    let request = http::Request::builder()
+        .uri("https://example.com/es/pricing?page=2&lang=es")
+        .header("Cookie", "theme=dark; lf-lang=es")
+        .header("Accept-Language", "en;q=0.5, es-ES")
+        .body(())
+        .unwrap();
+
+    assert_eq!(request.path(), "/es/pricing");
+    assert_eq!(request.query(), Some("page=2&lang=es"));
+    assert_eq!(request.path_and_query(), "/es/pricing?page=2&lang=es");
+    assert_eq!(request.url_param("lang"), Some("es"));
+    assert_eq!(request.url_param("theme"), None);
+    assert_eq!(request.cookie("lf-lang").as_deref(), Some("es"));
+    assert_eq!(request.cookie("lang"), None);
+    assert_eq!(request.accept_languages(), vec!["es-ES", "en"]);
+
+    let (parts, _) = request.into_parts();
+    assert_eq!(parts.path_and_query(), "/es/pricing?page=2&lang=es");
+    assert_eq!(parts.cookie("theme").as_deref(), Some("dark"));
+
+    Owner::new().with(|| {
+        provide_context(RequestSource::new(parts));
+        let source = expect_context::<RequestSource>();
+        assert_eq!(source.path(), "/es/pricing");
+        assert_eq!(source.header("accept-language"), Some("en;q=0.5, es-ES"));
+    });
+
+    let request = http::Request::builder().uri("/").body(()).unwrap();
+    assert_eq!(request.query(), None);
+    assert_eq!(request.path_and_query(), "/");
+    assert_eq!(request.url_param("lang"), None);
+    assert!(request.accept_languages().is_empty());
+}
@@ -43,3 +43,11 @@ pub fn input_by_id(id: &str) -> web_sys::HtmlInputElement {
         .unwrap()
         .unchecked_into::<web_sys::HtmlInputElement>()
 }
+
+pub fn element_attribute(selector: &str, name: &str) -> Option<String> {
+    gloo_utils::document()
+        .query_selector(selector)
+        .unwrap()
+        .unwrap()
+        .get_attribute(name)
+}
@@ -0,0 +1,133 @@
+use end2end_csr_helpers::{element_attribute, element_text, mount};
+use leptos::prelude::*;
+use leptos_fluent::components::{
+    LanguageLinks, LanguageRadioGroup, LanguageSelect,
+};
+use leptos_fluent::leptos_fluent;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys_ec::{Ec, Wait};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[component]
+fn I18n(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "./tests/selector-locales",
+        default_language: "en",
+    }
+}
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <I18n>
+            <div id="select">
+                <LanguageSelect />
+            </div>
+            <div id="radios">
+                <LanguageRadioGroup />
+            </div>
+            <div id="links">
+                // links to fragments don't reload the page
+                <LanguageLinks href=|lang| format!("#{}", lang.id) />
+            </div>
+        </I18n>
+    }
+}
+
+fn click(selector: &str) {
+    gloo_utils::document()
+        .query_selector(selector)
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<web_sys::HtmlElement>()
+        .click();
+}
+
+#[wasm_bindgen_test]
+pub async fn test_language_selectors() {
+    mount!(App);
+
+    // options define the language and writing direction of their names
+    for (selector, lang, dir) in [
+        ("#select option[value=en]", "en", "ltr"),
+        ("#select option[value=ar]", "ar", "rtl"),
+        ("#radios label:has(input[value=en])", "en", "ltr"),
+        ("#radios label:has(input[value=ar])", "ar", "rtl"),
+        ("#links a[hreflang=en]", "en", "ltr"),
+        ("#links a[hreflang=ar]", "ar", "rtl"),
+    ] {
+        assert_eq!(element_attribute(selector, "lang"), Some(lang.to_string()));
+        assert_eq!(element_attribute(selector, "dir"), Some(dir.to_string()));
+    }
+    assert_eq!(element_text("#select option[value=ar]"), "العربية");
+    assert_eq!(
+        element_text("#radios label:has(input[value=en])"),
+        "English"
+    );
+
+    // links point to each language
+    assert_eq!(
+        element_attribute("#links a[hreflang=ar]", "href"),
+        Some("#ar".to_string())
+    );
+    assert_eq!(
+        element_attribute("#links a[hreflang=en]", "aria-current"),
+        Some("true".to_string())
+    );
+    assert_eq!(
+        element_attribute("#links a[hreflang=ar]", "aria-current"),
+        None
+    );
+
+    // live regions are empty until the user changes the language
+    for selector in [
+        "#select [role=status]",
+        "#radios [role=status]",
+        "#links [role=status]",
+    ] {
+        assert_eq!(
+            element_attribute(selector, "aria-live"),
+            Some("polite".to_string())
+        );
+        assert_eq!(element_text(selector), "");
+        assert_eq!(element_attribute(selector, "lang"), None);
+    }
+
+    // the selected language is announced in the live region of its selector
+    click("#radios input[value=ar]");
+    Wait(1)
+        .until(("#radios [role=status]", Ec::InnerTextContains("العربية")))
+        .await;
+    assert_eq!(
+        element_attribute("#radios [role=status]", "lang"),
+        Some("ar".to_string())
+    );
+    assert_eq!(element_text("#links [role=status]"), "");
+    Wait(1)
+        .until((
+            "#links a[hreflang=ar]",
+            Ec::AttributeValueIs("aria-current", "true"),
+        ))
+        .await;
+    assert_eq!(
+        element_attribute("#links a[hreflang=en]", "aria-current"),
+        None
+    );
+
+    click("#links a[hreflang=en]");
+    Wait(1)
+        .until(("#links [role=status]", Ec::InnerTextContains("English")))
+        .await;
+    assert_eq!(
+        element_attribute("#links [role=status]", "lang"),
+        Some("en".to_string())
+    );
+    assert_eq!(
+        element_attribute("#links a[hreflang=en]", "aria-current"),
+        Some("true".to_string())
+    );
+    assert_eq!(element_text("#select [role=status]"), "");
+}
@@ -0,0 +1,2 @@
+hello = Hello
+only-english = Only in English
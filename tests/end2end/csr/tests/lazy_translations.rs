@@ -0,0 +1,103 @@
+use end2end_csr_helpers::{element_text, input_by_id, mount};
+use leptos::prelude::*;
+use leptos_fluent::{leptos_fluent, move_tr, I18n};
+use wasm_bindgen_test::*;
+use web_sys_ec::{Ec, Wait};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+// the test runner serves the files of the crate, so the FTL files are
+// fetched from the `tests/lazy-locales/` folder
+#[component]
+fn I18nProvider(children: Children) -> impl IntoView {
+    leptos_fluent! {
+        children: children(),
+        locales: "./tests/lazy-locales",
+        default_language: "en",
+        lazy_translations_url: "/tests/lazy-locales",
+    }
+}
+
+#[component]
+fn Page() -> impl IntoView {
+    let i18n = expect_context::<I18n>();
+
+    view! {
+        <p id="hello">{move_tr!("hello")}</p>
+        <p id="only-english">{move_tr!("only-english")}</p>
+        <p id="loading">{move || i18n.loading().get().to_string()}</p>
+        <fieldset>
+            {move || {
+                i18n.languages
+                    .iter()
+                    .map(|lang| {
+                        view! {
+                            <input
+                                type="radio"
+                                id=lang
+                                name="language"
+                                value=lang
+                                on:click=move |_| i18n.language.set(lang)
+                            />
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            }}
+        </fieldset>
+    }
+}
+
+#[component]
+fn App() -> impl IntoView {
+    view! {
+        <I18nProvider>
+            <Page />
+        </I18nProvider>
+    }
+}
+
+#[wasm_bindgen_test]
+pub async fn test_lazy_translations() {
+    mount!(App);
+    let en = move || input_by_id("en");
+    let es = move || input_by_id("es");
+    let fr = move || input_by_id("fr");
+
+    // the default language is embedded
+    assert_eq!(element_text("#hello"), "Hello");
+    assert_eq!(element_text("#loading"), "false");
+
+    // translations of other languages are fetched when selected
+    es().click();
+    Wait(2)
+        .until(("#hello", Ec::InnerTextContains("Hola")))
+        .await;
+    Wait(1)
+        .until(("#loading", Ec::InnerTextContains("false")))
+        .await;
+
+    // messages not found in fetched languages fallback to the embedded one
+    assert_eq!(element_text("#only-english"), "Only in English");
+
+    // switching languages while fetching doesn't mix their loading states
+    fr().click();
+    en().click();
+    Wait(1)
+        .until(("#hello", Ec::InnerTextContains("Hello")))
+        .await;
+    assert_eq!(element_text("#loading"), "false");
+    fr().click();
+    Wait(2)
+        .until(("#hello", Ec::InnerTextContains("Bonjour")))
+        .await;
+    Wait(1)
+        .until(("#loading", Ec::InnerTextContains("false")))
+        .await;
+
+    // fetched languages are not fetched again
+    es().click();
+    Wait(1)
+        .until(("#hello", Ec::InnerTextContains("Hola")))
+        .await;
+    assert_eq!(element_text("#loading"), "false");
+}
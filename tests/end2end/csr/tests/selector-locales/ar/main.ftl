@@ -0,0 +1 @@
+selected = اللغة المختارة
@@ -0,0 +1 @@
+selected = Selected language